@@ -92,17 +92,29 @@ fn translate_call<'cx, 'tcx>(
     let rs::Operand::Constant(box f1) = func else { panic!() };
     let rs::ConstantKind::Val(_, f2) = f1.literal else { panic!() };
     let rs::TyKind::FnDef(f, substs_ref) = f2.kind() else { panic!() };
-    let key = (*f, *substs_ref);
 
-    if fcx.cx.tcx.crate_name(f.krate).as_str() == "intrinsics" {
-        let intrinsic = match fcx.cx.tcx.item_name(*f).as_str() {
+    let intrinsic = if fcx.cx.tcx.crate_name(f.krate).as_str() == "intrinsics" {
+        Some(match fcx.cx.tcx.item_name(*f).as_str() {
             "print" => Intrinsic::PrintStdout,
             "eprint" => Intrinsic::PrintStderr,
             "exit" => Intrinsic::Exit,
             "allocate" => Intrinsic::Allocate,
             "deallocate" => Intrinsic::Deallocate,
             name => panic!("unsupported intrinsic `{}`", name),
-        };
+        })
+    } else if f2.fn_sig(fcx.cx.tcx).abi() == rs::Abi::RustIntrinsic {
+        // intrinsics of the Rust compiler, like `core::intrinsics::likely`.
+        Some(match fcx.cx.tcx.item_name(*f).as_str() {
+            "likely" => Intrinsic::Likely,
+            "unlikely" => Intrinsic::Unlikely,
+            "black_box" => Intrinsic::BlackBox,
+            name => panic!("unsupported Rust intrinsic `{}`", name),
+        })
+    } else {
+        None
+    };
+
+    if let Some(intrinsic) = intrinsic {
         Terminator::CallIntrinsic {
             intrinsic,
             arguments: args.iter().map(|x| translate_operand(x, fcx)).collect(),
@@ -110,19 +122,88 @@ fn translate_call<'cx, 'tcx>(
             next_block: target.as_ref().map(|t| fcx.bb_name_map[t]),
         }
     } else {
-        let (ret_abi, arg_abis) = calc_abis(*f, substs_ref, fcx.cx.tcx);
-        let args: List<_> = args.iter().map(|op| translate_operand(op, fcx)).collect();
+        // resolve trait methods (like `Fn::call` on a closure, or `Shape::area` on a struct)
+        // to the impl that is actually being called.
+        let instance = rs::Instance::resolve(fcx.cx.tcx, rs::ParamEnv::empty(), *f, substs_ref)
+            .unwrap()
+            .unwrap_or_else(|| panic!("could not resolve call to `{}`", fcx.cx.tcx.def_path_str(*f)));
+        let (ret_abi, arg_abis) = calc_abis(instance, fcx.cx.tcx);
+
+        let (callee, args) = if let rs::InstanceDef::Virtual(_, idx) = instance.def {
+            translate_virtual_call(idx, args, fcx)
+        } else if f2.fn_sig(fcx.cx.tcx).abi() == rs::Abi::RustCall {
+            let fn_name = fcx.cx.fn_name(instance);
+            (build::fn_ptr(fn_name.0.get_internal()), translate_rust_call_args(args, fcx))
+        } else {
+            let fn_name = fcx.cx.fn_name(instance);
+            let args = args.iter().map(|op| translate_operand(op, fcx)).collect();
+            (build::fn_ptr(fn_name.0.get_internal()), args)
+        };
 
-        if !fcx.cx.fn_name_map.contains_key(&key) {
-            let fn_name = fcx.cx.fn_name_map.len();
-            let fn_name = FnName(Name::from_internal(fn_name as _));
-            fcx.cx.fn_name_map.insert(key, fn_name);
-        }
         Terminator::Call {
-            callee: build::fn_ptr(fcx.cx.fn_name_map[&key].0.get_internal()),
+            callee,
             arguments: args.zip(arg_abis),
             ret: Some((translate_place(&destination, fcx), ret_abi)),
             next_block: target.as_ref().map(|t| fcx.bb_name_map[t]),
+            unwind_block: None,
         }
     }
 }
+
+/// The "rust-call" ABI passes the last argument as a tuple in MIR,
+/// but the callee expects the tuple elements as separate arguments.
+fn translate_rust_call_args<'tcx>(
+    args: &[rs::Operand<'tcx>],
+    fcx: &mut FnCtxt<'_, 'tcx>,
+) -> List<ValueExpr> {
+    let (tupled, args) = args.split_last().unwrap();
+    let mut args: List<_> = args.iter().map(|op| translate_operand(op, fcx)).collect();
+
+    let ty = tupled.ty(&fcx.body, fcx.cx.tcx);
+    let rs::TyKind::Tuple(elems) = ty.kind() else { panic!("rust-call argument is not a tuple") };
+    if elems.is_empty() {
+        return args;
+    }
+
+    let (rs::Operand::Copy(place) | rs::Operand::Move(place)) = tupled else {
+        panic!("unsupported constant rust-call argument")
+    };
+    let destructive = matches!(tupled, rs::Operand::Move(_));
+    let root = translate_place(place, fcx);
+    for i in 0..elems.len() {
+        let field = PlaceExpr::Field {
+            root: GcCow::new(root),
+            field: i.into(),
+        };
+        args.push(ValueExpr::Load {
+            destructive,
+            source: GcCow::new(field),
+        });
+    }
+
+    args
+}
+
+/// A virtual call loads the function pointer from the vtable of its receiver,
+/// and only passes the data pointer of the receiver.
+fn translate_virtual_call<'tcx>(
+    idx: usize,
+    args: &[rs::Operand<'tcx>],
+    fcx: &mut FnCtxt<'_, 'tcx>,
+) -> (ValueExpr, List<ValueExpr>) {
+    let (receiver, args) = args.split_first().unwrap();
+    let (rs::Operand::Copy(place) | rs::Operand::Move(place)) = receiver else {
+        panic!("unsupported constant receiver of a virtual call")
+    };
+    let receiver = translate_place(place, fcx);
+    let (data_ptr, vtable_ptr) = split_fat_ptr(receiver);
+
+    let callee = vtable_method(vtable_ptr, idx);
+
+    let mut arguments = list![data_ptr];
+    for arg in args {
+        arguments.push(translate_operand(arg, fcx));
+    }
+
+    (callee, arguments)
+}
@@ -18,6 +18,11 @@ fn translate_const_val<'cx, 'tcx>(
 ) -> ValueExpr {
     let ty = translate_ty(ty, fcx.cx.tcx);
 
+    // A string literal.
+    if let rs::ConstValue::Slice { data, start, end } = val {
+        return translate_str_literal(data, start, end, fcx);
+    }
+
     let constant = match ty {
         Type::Int(int_ty) => {
             let val = val.try_to_scalar_int().unwrap();
@@ -48,6 +53,22 @@ fn translate_const_val<'cx, 'tcx>(
     ValueExpr::Constant(constant, ty)
 }
 
+// The bytes of a string literal become a global, and the `&str` points into it.
+fn translate_str_literal<'tcx>(
+    data: rs::ConstAllocation<'tcx>,
+    start: usize,
+    end: usize,
+    fcx: &mut FnCtxt<'_, 'tcx>,
+) -> ValueExpr {
+    let name = fresh_global_name(fcx);
+    translate_const_allocation(data, fcx, name);
+
+    let rel = Relocation { name, offset: Size::from_bytes(start).unwrap() };
+    let data_ptr = ValueExpr::Constant(Constant::GlobalPointer(rel), component_ptr_ty());
+    let len = build::const_int::<usize>(end - start);
+    ValueExpr::Tuple(list![data_ptr, len], str_ptr_ty())
+}
+
 fn translate_const_uneval<'cx, 'tcx>(
     uneval: rs::UnevaluatedConst<'tcx>,
     ty: rs::Ty<'tcx>,
@@ -81,7 +102,7 @@ fn relocation_to_value_expr<'cx, 'tcx>(
 
     let pty = place_type_of(ty, fcx);
     let ptr_ty = Type::Ptr(PtrType::Raw {
-        pointee: pty.layout::<BasicMemory>(),
+        pointee: pty.layout::<BasicMemory<DefaultTarget>>(),
     });
 
     let expr = ValueExpr::Constant(expr, ptr_ty);
@@ -155,10 +176,10 @@ fn translate_const_allocation<'cx, 'tcx>(
             // "Note that the bytes of a pointer represent the offset of the pointer.", see https://doc.rust-lang.org/nightly/nightly-rustc/rustc_middle/mir/interpret/struct.Allocation.html
             // Hence we have to decode them.
             let inner_offset_bytes: &[Option<u8>] = &bytes[offset.bytes() as usize..]
-                [..BasicMemory::PTR_SIZE.bytes().try_to_usize().unwrap()];
+                [..DefaultTarget::PTR_SIZE.bytes().try_to_usize().unwrap()];
             let inner_offset_bytes: List<u8> =
                 inner_offset_bytes.iter().map(|x| x.unwrap()).collect();
-            let inner_offset: Int = BasicMemory::ENDIANNESS.decode(Unsigned, inner_offset_bytes);
+            let inner_offset: Int = DefaultTarget::ENDIANNESS.decode(Unsigned, inner_offset_bytes);
             let inner_offset = rs::Size::from_bytes(inner_offset.try_to_usize().unwrap());
             let relo = translate_relocation(alloc_id, inner_offset, fcx);
 
@@ -171,18 +192,20 @@ fn translate_const_allocation<'cx, 'tcx>(
         bytes: bytes.into_iter().collect(),
         relocations,
         align,
+        thread_local: false,
     };
 
     fcx.cx.globals.insert(name, global);
 }
 
-fn fresh_global_name<'cx, 'tcx>(fcx: &mut FnCtxt<'cx, 'tcx>) -> GlobalName {
+pub fn fresh_global_name<'cx, 'tcx>(fcx: &mut FnCtxt<'cx, 'tcx>) -> GlobalName {
     let name = GlobalName(Name::from_internal(fcx.cx.globals.iter().count() as _)); // TODO use .len() here, if supported
                                                                                     // the default_global is added so that calling `fresh_global_name` twice returns different names.
     let default_global = Global {
         bytes: Default::default(),
         relocations: Default::default(),
         align: Align::ONE,
+        thread_local: false,
     };
     fcx.cx.globals.insert(name, default_global);
     name
@@ -4,7 +4,7 @@ use crate::*;
 use rustc_driver::{Callbacks, Compilation, RunCompiler};
 use rustc_interface::{interface::Compiler, Queries};
 
-pub fn get_mini(file: String, callback: impl FnOnce(Program) + Send + Copy) {
+pub fn get_mini(file: String, callback: impl FnOnce(Program, FnSourceNames) + Send + Copy) {
     if !Path::new(&file).exists() {
         eprintln!("File `{file}` not found.");
         std::process::exit(1);
@@ -32,19 +32,19 @@ pub fn get_mini(file: String, callback: impl FnOnce(Program) + Send + Copy) {
     RunCompiler::new(&args, &mut Cb { callback }).run().unwrap();
 }
 
-struct Cb<F: FnOnce(Program) + Send + Copy> {
+struct Cb<F: FnOnce(Program, FnSourceNames) + Send + Copy> {
     callback: F,
 }
 
-impl<F: FnOnce(Program) + Send + Copy> Callbacks for Cb<F> {
+impl<F: FnOnce(Program, FnSourceNames) + Send + Copy> Callbacks for Cb<F> {
     fn after_analysis<'tcx>(
         &mut self,
         _compiler: &Compiler,
         queries: &'tcx Queries<'tcx>,
     ) -> Compilation {
         queries.global_ctxt().unwrap().enter(|arg| {
-            let prog = Ctxt::new(arg).translate();
-            (self.callback)(prog);
+            let (prog, source_names) = Ctxt::new(arg).translate();
+            (self.callback)(prog, source_names);
         });
 
         Compilation::Stop
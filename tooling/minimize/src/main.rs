@@ -18,6 +18,7 @@ mod rs {
     pub use rustc_middle::ty::*;
     pub use rustc_mir_dataflow::storage::always_storage_live_locals;
     pub use rustc_target::abi::{call::*, Align, Size, FieldIdx};
+    pub use rustc_target::spec::abi::Abi;
 }
 
 pub use minirust_rs::libspecr::hidden::*;
@@ -33,7 +34,7 @@ pub use std::format;
 pub use std::string::String;
 
 pub use miniutil::build;
-pub use miniutil::fmt::dump_program;
+pub use miniutil::fmt::{dump_program, fmt_fn_name, fmt_function_with_callees, fmt_termination_info};
 pub use miniutil::run::*;
 
 mod program;
@@ -51,6 +52,9 @@ use rvalue::*;
 mod constant;
 use constant::*;
 
+mod vtable;
+use vtable::*;
+
 mod get;
 use get::get_mini;
 
@@ -61,22 +65,62 @@ use std::collections::HashMap;
 use std::path::Path;
 
 fn main() {
-    let file = std::env::args()
-        .skip(1)
-        .filter(|x| !x.starts_with('-'))
+    let args: Vec<String> = std::env::args().skip(1).collect();
+
+    // `--dump-fn <name>` only dumps the functions with the given source name.
+    let dump_fn_idx = args.iter().position(|x| x == "--dump-fn");
+    let dump_fn = dump_fn_idx.map(|i| {
+        args.get(i + 1).cloned().unwrap_or_else(|| {
+            eprintln!("`--dump-fn` requires a function name.");
+            std::process::exit(1);
+        })
+    });
+
+    let file = args
+        .iter()
+        .enumerate()
+        .filter(|(i, x)| !x.starts_with('-') && dump_fn_idx.map_or(true, |d| *i != d + 1))
+        .map(|(_, x)| x.clone())
         .next()
         .unwrap_or_else(|| String::from("file.rs"));
 
-    get_mini(file, |prog| {
-        let dump = std::env::args().skip(1).any(|x| x == "--dump");
-        if dump {
+    let dump = args.iter().any(|x| x == "--dump");
+
+    // `--list-fns` prints each lowered function with its source name and number of basic blocks.
+    let list_fns = args.iter().any(|x| x == "--list-fns");
+    if list_fns && (dump || dump_fn.is_some()) {
+        eprintln!("`--list-fns` cannot be combined with `--dump` or `--dump-fn`.");
+        std::process::exit(1);
+    }
+
+    get_mini(file, |prog, source_names| {
+        if let Some(name) = &dump_fn {
+            let mut fn_names: Vec<FnName> = source_names
+                .iter()
+                .filter(|(source_name, _)| source_name == name)
+                .map(|(_, fn_name)| *fn_name)
+                .collect();
+            fn_names.sort_by_key(|FnName(name)| *name);
+            if fn_names.is_empty() {
+                eprintln!("no function named `{name}`.");
+                std::process::exit(1);
+            }
+            for fn_name in fn_names {
+                println!("{}", fmt_function_with_callees(prog, fn_name));
+            }
+        } else if list_fns {
+            let mut source_names = source_names;
+            source_names.sort_by_key(|(_, FnName(name))| *name);
+            for (source_name, fn_name) in source_names {
+                let blocks = prog.functions.get(fn_name).unwrap().blocks.len();
+                println!("{}: {source_name} ({blocks} blocks)", fmt_fn_name(fn_name));
+            }
+        } else if dump {
             dump_program(prog);
         } else {
             match run_program(prog) {
-                TerminationInfo::IllFormed => eprintln!("ERR: program not well-formed."),
-                TerminationInfo::MachineStop => { /* silent exit. */ }
-                TerminationInfo::Ub(err) => eprintln!("UB: {}", err.get_internal()),
-                _ => unreachable!(),
+                TerminationInfo::MachineStop { .. } => { /* silent exit. */ }
+                info => eprintln!("{}", fmt_termination_info(info)),
             }
         }
     });
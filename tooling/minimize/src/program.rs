@@ -1,15 +1,26 @@
 use crate::*;
 
+/// The source name (e.g. `foo` or `Foo::bar`) of each translated function.
+/// Generic functions have one entry for each instance.
+pub type FnSourceNames = Vec<(String, FnName)>;
+
 pub struct Ctxt<'tcx> {
     pub tcx: rs::TyCtxt<'tcx>,
 
-    /// maps Rust function calls to MiniRust FnNames.
-    pub fn_name_map: HashMap<(rs::DefId, rs::SubstsRef<'tcx>), FnName>,
+    /// maps Rust function instances to MiniRust FnNames.
+    pub fn_name_map: HashMap<rs::Instance<'tcx>, FnName>,
 
     /// Stores which AllocId evaluates to which GlobalName.
     /// Note that not every AllocId and not every GlobalName is coming up in this map (for example constants are missing).
     pub alloc_map: HashMap<rs::AllocId, GlobalName>,
 
+    /// Stores the vtable global for each (type, trait) pair coerced to a trait object.
+    pub vtable_map: HashMap<(rs::Ty<'tcx>, Option<rs::PolyExistentialTraitRef<'tcx>>), GlobalName>,
+
+    /// The function pointers stored in vtables.
+    /// Function addresses are not known statically, so the `start` function writes them into the vtables.
+    pub vtable_fns: Vec<(Relocation, FnName)>,
+
     pub globals: Map<GlobalName, Global>,
 
     pub functions: Map<FnName, Function>,
@@ -21,17 +32,30 @@ impl<'tcx> Ctxt<'tcx> {
             tcx,
             fn_name_map: Default::default(),
             alloc_map: Default::default(),
+            vtable_map: Default::default(),
+            vtable_fns: Default::default(),
             globals: Default::default(),
             functions: Default::default(),
         }
     }
 
-    pub fn translate(mut self) -> Program {
+    /// returns the FnName of `instance`, registering it for translation if necessary.
+    pub fn fn_name(&mut self, instance: rs::Instance<'tcx>) -> FnName {
+        if let Some(fn_name) = self.fn_name_map.get(&instance) {
+            return *fn_name;
+        }
+        let fn_name = FnName(Name::from_internal(self.fn_name_map.len() as _));
+        self.fn_name_map.insert(instance, fn_name);
+        fn_name
+    }
+
+    /// Translates the program, and also returns the source name of each function.
+    pub fn translate(mut self) -> (Program, FnSourceNames) {
         let (entry, _ty) = self.tcx.entry_fn(()).unwrap();
-        let substs_ref: rs::SubstsRef<'tcx> = self.tcx.mk_substs(&[]);
+        let entry_instance = rs::Instance::mono(self.tcx, entry);
         let entry_name = FnName(Name::from_internal(0));
 
-        self.fn_name_map.insert((entry, substs_ref), entry_name);
+        self.fn_name_map.insert(entry_instance, entry_name);
 
         // take any not-yet-implemented function:
         while let Some(fn_name) = self
@@ -40,14 +64,14 @@ impl<'tcx> Ctxt<'tcx> {
             .find(|k| !self.functions.contains_key(**k))
             .copied()
         {
-            let (def_id, substs_ref) = self
+            let instance = self
                 .fn_name_map
                 .iter()
                 .find(|(_, f)| **f == fn_name)
-                .map(|(r, _)| r)
+                .map(|(r, _)| *r)
                 .unwrap();
 
-            let f = FnCtxt::new(*def_id, substs_ref, &mut self).translate();
+            let f = FnCtxt::new(instance, &mut self).translate();
             self.functions.insert(fn_name, f);
         }
 
@@ -55,27 +79,54 @@ impl<'tcx> Ctxt<'tcx> {
 
         // add a `start` function, which calls `entry`.
         let start = FnName(Name::from_internal(number_of_fns as _));
-        self.functions.insert(start, mk_start_fn(0));
+        self.functions.insert(start, mk_start_fn(0, &self.vtable_fns));
 
-        Program {
+        let prog = Program {
             start,
             functions: self.functions,
             globals: self.globals,
-        }
+        };
+
+        let source_names = self
+            .fn_name_map
+            .iter()
+            .map(|(instance, fn_name)| (self.tcx.def_path_str(instance.def_id()), *fn_name))
+            .collect();
+
+        (prog, source_names)
     }
 }
 
-fn mk_start_fn(entry: u32) -> Function {
+fn mk_start_fn(entry: u32, vtable_fns: &[(Relocation, FnName)]) -> Function {
     let b0_name = BbName(Name::from_internal(0));
     let b1_name = BbName(Name::from_internal(1));
 
+    use build::TypeConv;
+
+    // fill the function pointers of all vtables.
+    let fn_ptr_ty = Type::Ptr(PtrType::FnPtr);
+    let statements = vtable_fns
+        .iter()
+        .map(|&(rel, fn_name)| {
+            let slot = ValueExpr::Constant(
+                Constant::GlobalPointer(rel),
+                build::raw_ptr_ty(<usize>::get_layout()),
+            );
+            Statement::Assign {
+                destination: build::deref(slot, build::ptype(fn_ptr_ty, DefaultTarget::PTR_ALIGN)),
+                source: ValueExpr::Constant(Constant::FnPointer(fn_name), fn_ptr_ty),
+            }
+        })
+        .collect();
+
     let b0 = BasicBlock {
-        statements: List::new(),
+        statements,
         terminator: Terminator::Call {
             callee: build::fn_ptr(entry),
             arguments: List::new(),
             ret: None,
             next_block: Some(b1_name),
+            unwind_block: None,
         },
     };
 
@@ -106,8 +157,7 @@ fn mk_start_fn(entry: u32) -> Function {
 pub struct FnCtxt<'cx, 'tcx> {
     // the body we intend to translate.
     pub body: rs::Body<'tcx>,
-    pub def_id: rs::DefId,
-    pub substs_ref: rs::SubstsRef<'tcx>,
+    pub instance: rs::Instance<'tcx>,
 
     pub cx: &'cx mut Ctxt<'tcx>,
 
@@ -122,22 +172,19 @@ pub struct FnCtxt<'cx, 'tcx> {
 }
 
 impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
-    pub fn new(
-        def_id: rs::DefId,
-        substs_ref: rs::SubstsRef<'tcx>,
-        cx: &'cx mut Ctxt<'tcx>,
-    ) -> Self {
-        let body = cx.tcx.optimized_mir(def_id);
-        let body = cx.tcx.subst_and_normalize_erasing_regions(
-            substs_ref,
+    pub fn new(instance: rs::Instance<'tcx>, cx: &'cx mut Ctxt<'tcx>) -> Self {
+        // `instance_mir` also provides the bodies of compiler-generated shims,
+        // like the `FnOnce` shim of a closure.
+        let body = cx.tcx.instance_mir(instance.def);
+        let body = instance.subst_mir_and_normalize_erasing_regions(
+            cx.tcx,
             rs::ParamEnv::empty(),
             rs::EarlyBinder::bind(body.clone()),
         );
 
         FnCtxt {
             body,
-            def_id,
-            substs_ref,
+            instance,
             cx,
             local_name_map: Default::default(),
             bb_name_map: Default::default(),
@@ -176,19 +223,53 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         // the number of locals which are implicitly storage live.
         let free_argc = self.body.arg_count + 1;
 
+        // "The first local is the return value pointer, followed by arg_count locals for the function arguments, followed by any user-declared variables and temporaries."
+        // - https://doc.rust-lang.org/stable/nightly-rustc/rustc_middle/mir/struct.Body.html
+        let mut arg_locals: Vec<LocalName> = (1..free_argc)
+            .map(|i| LocalName(Name::from_internal(i as _)))
+            .collect();
+
         // add init basic block
         let init_bb = BbName(Name::from_internal(self.bb_name_map.len() as u32));
 
         // this block allocates all "always_storage_live_locals",
         // except for those which are implicitly storage live in Minirust;
         // like the return local and function args.
+        let mut init_stmts: List<Statement> = rs::always_storage_live_locals(&self.body)
+            .iter()
+            .map(|loc| self.local_name_map[&loc])
+            .filter(|LocalName(i)| i.get_internal() as usize >= free_argc)
+            .map(Statement::StorageLive)
+            .collect();
+
+        // Bodies with a `spread_arg` (like closure shims) receive their last argument untupled.
+        // Each tuple element is passed in a fresh local, and the tuple is re-assembled in the init block.
+        if let Some(spread) = self.body.spread_arg {
+            let spread_name = arg_locals.pop().unwrap();
+            let spread_ty = self.body.local_decls[spread].ty;
+            let rs::TyKind::Tuple(elem_tys) = spread_ty.kind() else { panic!() };
+
+            let mut elems = List::new();
+            for elem_ty in elem_tys.iter() {
+                let local_name = LocalName(Name::from_internal(self.locals.len().try_to_usize().unwrap() as u32));
+                let place_ty = place_type_of(elem_ty, &mut self);
+                self.locals.insert(local_name, place_ty);
+                arg_locals.push(local_name);
+                elems.push(ValueExpr::Load {
+                    destructive: true,
+                    source: GcCow::new(PlaceExpr::Local(local_name)),
+                });
+            }
+
+            init_stmts.push(Statement::StorageLive(spread_name));
+            init_stmts.push(Statement::Assign {
+                destination: PlaceExpr::Local(spread_name),
+                source: ValueExpr::Tuple(elems, translate_ty(spread_ty, self.cx.tcx)),
+            });
+        }
+
         let init_blk = BasicBlock {
-            statements: rs::always_storage_live_locals(&self.body)
-                .iter()
-                .map(|loc| self.local_name_map[&loc])
-                .filter(|LocalName(i)| i.get_internal() as usize >= free_argc)
-                .map(Statement::StorageLive)
-                .collect(),
+            statements: init_stmts,
             terminator: Terminator::Goto(rs_start),
         };
 
@@ -201,18 +282,16 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
         }
         self.blocks.insert(init_bb, init_blk);
 
-        let (ret_abi, arg_abis) = calc_abis(self.def_id, self.substs_ref, self.cx.tcx);
+        let (ret_abi, arg_abis) = calc_abis(self.instance, self.cx.tcx);
 
-        // "The first local is the return value pointer, followed by arg_count locals for the function arguments, followed by any user-declared variables and temporaries."
-        // - https://doc.rust-lang.org/stable/nightly-rustc/rustc_middle/mir/struct.Body.html
-        let ret = Some((LocalName(Name::from_internal(0)), ret_abi));
+        // Functions returning `!` cannot return, so they do not get a return local.
+        let ret = match self.body.return_ty().is_never() {
+            true => None,
+            false => Some((LocalName(Name::from_internal(0)), ret_abi)),
+        };
 
-        let mut args = List::default();
-        for (i, arg_abi) in arg_abis.iter().enumerate() {
-            let i = i + 1; // this starts counting with 1, as id 0 is the return value of the function.
-            let local_name = LocalName(Name::from_internal(i as _));
-            args.push((local_name, arg_abi));
-        }
+        assert_eq!(arg_locals.len(), arg_abis.len().try_to_usize().unwrap());
+        let args = arg_locals.into_iter().collect::<List<_>>().zip(arg_abis);
 
         let f = Function {
             locals: self.locals,
@@ -228,11 +307,9 @@ impl<'cx, 'tcx> FnCtxt<'cx, 'tcx> {
 
 // TODO simplify this function.
 pub fn calc_abis<'tcx>(
-    def_id: rs::DefId,
-    substs_ref: rs::SubstsRef<'tcx>,
+    instance: rs::Instance<'tcx>,
     tcx: rs::TyCtxt<'tcx>,
 ) -> (/*ret:*/ ArgAbi, /*args:*/ List<ArgAbi>) {
-    let instance = rs::Instance::resolve(tcx, rs::ParamEnv::empty(), def_id, substs_ref).unwrap().unwrap();
     let fn_abi = tcx.fn_abi_of_instance(rs::ParamEnv::empty().and((instance, rs::List::empty()))).unwrap();
     let ret = translate_arg_abi(&fn_abi.ret);
     let args = fn_abi.args.iter().map(|x| translate_arg_abi(x)).collect();
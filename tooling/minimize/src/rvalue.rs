@@ -11,10 +11,24 @@ pub fn translate_rvalue<'cx, 'tcx>(
             let lty = l.ty(&fcx.body, fcx.cx.tcx);
             let rty = r.ty(&fcx.body, fcx.cx.tcx);
 
-            assert_eq!(lty, rty);
+            if *bin_op != rs::BinOp::Offset {
+                assert_eq!(lty, rty);
+            }
 
             let l = translate_operand(l, fcx);
-            let r = translate_operand(r, fcx);
+            let mut r = translate_operand(r, fcx);
+
+            if *bin_op == rs::BinOp::Offset {
+                // MIR offsets count elements, MiniRust offsets count bytes.
+                let pointee = lty.builtin_deref(true).unwrap().ty;
+                let elem_size = layout_of(pointee, fcx.cx.tcx).size;
+                let Type::Int(int_ty) = translate_ty(rty, fcx.cx.tcx) else { panic!("non-integer offset") };
+                r = ValueExpr::BinOp {
+                    operator: BinOp::Int(BinOpInt::Mul, int_ty),
+                    left: GcCow::new(r),
+                    right: GcCow::new(ValueExpr::Constant(Constant::Int(elem_size.bytes()), Type::Int(int_ty))),
+                };
+            }
 
             let l = GcCow::new(l);
             let r = GcCow::new(r);
@@ -22,6 +36,11 @@ pub fn translate_rvalue<'cx, 'tcx>(
             use rs::BinOp::*;
             let op = if *bin_op == Offset {
                 BinOp::PtrOffset { inbounds: true }
+            } else if lty.is_unsafe_ptr() && matches!(bin_op, Eq | Ne) {
+                match bin_op {
+                    Eq => BinOp::PtrRel(PtrRel::Eq),
+                    _ => BinOp::PtrRel(PtrRel::Ne),
+                }
             } else {
                 // everything else right-now is a int op!
 
@@ -79,6 +98,17 @@ pub fn translate_rvalue<'cx, 'tcx>(
             }
             _ => panic!("unsupported UnOp!"),
         },
+        rs::Rvalue::Ref(_, _, place) | rs::Rvalue::AddressOf(_, place) if is_fat_reborrow(place, fcx) => {
+            // re-borrowing a trait object or a `str` copies the fat pointer.
+            let ptr_place = rs::Place {
+                local: place.local,
+                projection: fcx.cx.tcx.mk_place_elems(&place.projection[..place.projection.len() - 1]),
+            };
+            ValueExpr::Load {
+                destructive: false,
+                source: GcCow::new(translate_place(&ptr_place, fcx)),
+            }
+        }
         rs::Rvalue::Ref(_, bkind, place) => {
             let ty = place.ty(&fcx.body, fcx.cx.tcx).ty;
             let pointee = layout_of(ty, fcx.cx.tcx);
@@ -136,13 +166,21 @@ pub fn translate_rvalue<'cx, 'tcx>(
             ValueExpr::Constant(Constant::Int(count), <usize>::get_type())
         }
         rs::Rvalue::Cast(rs::CastKind::IntToInt, operand, ty) => {
+            let operand_ty = operand.ty(&fcx.body, fcx.cx.tcx);
             let operand = translate_operand(operand, fcx);
             let Type::Int(int_ty) = translate_ty(*ty, fcx.cx.tcx) else {
                 panic!("attempting to IntToInt-Cast to non-int type!");
             };
 
+            // `bool as int` is also an `IntToInt` cast in MIR.
+            let operator = if operand_ty.is_bool() {
+                UnOp::Bool2Int(int_ty)
+            } else {
+                UnOp::Int(UnOpInt::Cast, int_ty)
+            };
+
             ValueExpr::UnOp {
-                operator: UnOp::Int(UnOpInt::Cast, int_ty),
+                operator,
                 operand: GcCow::new(operand),
             }
         }
@@ -155,30 +193,69 @@ pub fn translate_rvalue<'cx, 'tcx>(
             }
         }
         rs::Rvalue::Cast(rs::CastKind::PointerFromExposedAddress, operand, ty) => {
-            // TODO untested so far! (Can't test because of `predict`)
             let operand = translate_operand(operand, fcx);
             let Type::Ptr(ptr_ty) = translate_ty(*ty, fcx.cx.tcx) else { panic!() };
 
             ValueExpr::UnOp {
-                operator: UnOp::Int2Ptr(ptr_ty),
+                operator: UnOp::Int2Ptr(ptr_ty, Int2PtrProvenance::Exposed),
                 operand: GcCow::new(operand),
             }
         }
         rs::Rvalue::Cast(rs::CastKind::PtrToPtr, operand, ty) => {
-            let operand = translate_operand(operand, fcx);
-            let Type::Ptr(ptr_ty) = translate_ty(*ty, fcx.cx.tcx) else { panic!() };
+            let operand_ty = translate_ty(operand.ty(&fcx.body, fcx.cx.tcx), fcx.cx.tcx);
+            let operand = match (operand, operand_ty) {
+                // casting a fat pointer to a thin pointer keeps only the data pointer.
+                (rs::Operand::Copy(place) | rs::Operand::Move(place), Type::Tuple { .. }) => {
+                    let place = translate_place(place, fcx);
+                    build::load(build::field(place, 0))
+                }
+                (_, Type::Tuple { .. }) => panic!("unsupported cast of a fat pointer constant"),
+                _ => translate_operand(operand, fcx),
+            };
+            let ty = translate_ty(*ty, fcx.cx.tcx);
+            if let Type::Tuple { .. } = ty {
+                // fat-to-fat pointer casts do not change the representation.
+                return Some(operand);
+            }
+            let Type::Ptr(ptr_ty) = ty else { panic!() };
 
             ValueExpr::UnOp {
                 operator: UnOp::Ptr2Ptr(ptr_ty),
                 operand: GcCow::new(operand),
             }
         }
+        rs::Rvalue::Cast(rs::CastKind::Pointer(rs::adjustment::PointerCast::Unsize), operand, ty) => {
+            // the coercion of a thin pointer to a trait object pointer.
+            let source = operand.ty(&fcx.body, fcx.cx.tcx).builtin_deref(true).unwrap().ty;
+            let target = ty.builtin_deref(true).unwrap().ty;
+            let rs::TyKind::Dynamic(preds, _, _) = target.kind() else {
+                panic!("unsupported unsizing coercion to {:?}", target)
+            };
+
+            let data_ptr = translate_operand(operand, fcx);
+            let vtable_ptr = vtable_ptr(source, preds.principal(), fcx);
+            fat_ptr(data_ptr, vtable_ptr)
+        }
+        rs::Rvalue::Cast(rs::CastKind::Transmute, operand, ty) => {
+            let operand = translate_operand(operand, fcx);
+            let ty = translate_ty(*ty, fcx.cx.tcx);
+
+            ValueExpr::UnOp {
+                operator: UnOp::Transmute(ty),
+                operand: GcCow::new(operand),
+            }
+        }
         rs::Rvalue::Repeat(op, c) => {
             let c = c.try_eval_target_usize(fcx.cx.tcx, rs::ParamEnv::empty()).unwrap();
             let c = Int::from(c);
 
             let elem_ty = translate_ty(op.ty(&fcx.body, fcx.cx.tcx), fcx.cx.tcx);
-            let op = translate_operand(op, fcx);
+            // The operand is evaluated once per element, so a destructive load would leave it
+            // uninitialized for all but the first element. Repeated values are `Copy` anyway.
+            let op = match op {
+                rs::Operand::Move(place) => translate_operand(&rs::Operand::Copy(*place), fcx),
+                _ => translate_operand(op, fcx),
+            };
 
             let ty = Type::Array {
                 elem: GcCow::new(elem_ty),
@@ -263,6 +340,13 @@ pub fn translate_place<'cx, 'tcx>(
     expr
 }
 
+// whether `place` is the pointee of a fat pointer, i.e. a trait object or a `str`.
+fn is_fat_reborrow<'tcx>(place: &rs::Place<'tcx>, fcx: &mut FnCtxt<'_, 'tcx>) -> bool {
+    let ty = place.ty(&fcx.body, fcx.cx.tcx).ty;
+    matches!(place.projection.last(), Some(rs::ProjectionElem::Deref))
+        && matches!(ty.kind(), rs::TyKind::Dynamic(..) | rs::TyKind::Str)
+}
+
 pub fn place_type_of<'cx, 'tcx>(ty: rs::Ty<'tcx>, fcx: &mut FnCtxt<'cx, 'tcx>) -> PlaceType {
     let align = layout_of(ty, fcx.cx.tcx).align;
     let ty = translate_ty(ty, fcx.cx.tcx);
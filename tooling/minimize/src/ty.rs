@@ -45,6 +45,27 @@ pub fn translate_ty<'tcx>(ty: rs::Ty<'tcx>, tcx: rs::TyCtxt<'tcx>) -> Type {
 
             Type::Tuple { fields, size }
         }
+        rs::TyKind::Closure(_, substs) => {
+            // a closure is represented by the tuple of its captured variables.
+            let a = rs::ParamEnv::empty().and(ty);
+            let layout = tcx.layout_of(a).unwrap().layout;
+            let size = translate_size(layout.size());
+
+            let fields = substs
+                .as_closure()
+                .upvar_tys()
+                .enumerate()
+                .map(|(i, t)| {
+                    let t = translate_ty(t, tcx);
+                    let offset = layout.fields().offset(i);
+                    let offset = translate_size(offset);
+
+                    (offset, t)
+                })
+                .collect();
+
+            Type::Tuple { fields, size }
+        }
         rs::TyKind::Adt(adt_def, sref) if adt_def.is_struct() => {
             let (fields, size) = translate_adt_fields(ty, *adt_def, sref, tcx);
 
@@ -62,15 +83,24 @@ pub fn translate_ty<'tcx>(ty: rs::Ty<'tcx>, tcx: rs::TyCtxt<'tcx>) -> Type {
         }
         rs::TyKind::Adt(adt_def, _) if adt_def.is_box() => {
             let ty = ty.boxed_ty();
+            if let Some(fat_ptr_ty) = translate_unsized_ptr(ty) {
+                return fat_ptr_ty;
+            }
             let pointee = layout_of(ty, tcx);
             Type::Ptr(PtrType::Box { pointee })
         }
         rs::TyKind::Ref(_, ty, mutbl) => {
+            if let Some(fat_ptr_ty) = translate_unsized_ptr(*ty) {
+                return fat_ptr_ty;
+            }
             let pointee = layout_of(*ty, tcx);
             let mutbl = translate_mutbl(*mutbl);
             Type::Ptr(PtrType::Ref { pointee, mutbl })
         }
         rs::TyKind::RawPtr(rs::TypeAndMut { ty, mutbl: _ }) => {
+            if let Some(fat_ptr_ty) = translate_unsized_ptr(*ty) {
+                return fat_ptr_ty;
+            }
             let pointee = layout_of(*ty, tcx);
             Type::Ptr(PtrType::Raw { pointee })
         }
@@ -79,6 +109,7 @@ pub fn translate_ty<'tcx>(ty: rs::Ty<'tcx>, tcx: rs::TyCtxt<'tcx>) -> Type {
             let elem = GcCow::new(translate_ty(*ty, tcx));
             Type::Array { elem, count }
         }
+        rs::TyKind::Never => build::never_ty(),
         x => {
             dbg!(x);
             todo!()
@@ -86,6 +117,16 @@ pub fn translate_ty<'tcx>(ty: rs::Ty<'tcx>, tcx: rs::TyCtxt<'tcx>) -> Type {
     }
 }
 
+// returns the type of a pointer to `pointee`, if that pointer carries metadata.
+fn translate_unsized_ptr(pointee: rs::Ty<'_>) -> Option<Type> {
+    match pointee.kind() {
+        rs::TyKind::Dynamic(..) => Some(fat_ptr_ty()),
+        rs::TyKind::Str => Some(str_ptr_ty()),
+        rs::TyKind::Slice(_) => panic!("unsupported pointer to a slice: {:?}", pointee),
+        _ => None,
+    }
+}
+
 fn translate_adt_fields<'tcx>(
     ty: rs::Ty<'tcx>,
     adt_def: rs::AdtDef<'tcx>,
@@ -115,7 +156,7 @@ fn translate_int_ty(int_ty: &rs::IntTy) -> IntType {
     use rs::IntTy::*;
 
     let size = match int_ty {
-        Isize => 8, // this is fixed as 8, to be compatible with BasicMemory.
+        Isize => 8, // this is fixed as 8, to be compatible with `DefaultTarget`.
         I8 => 1,
         I16 => 2,
         I32 => 4,
@@ -132,7 +173,7 @@ fn translate_uint_ty(uint_ty: &rs::UintTy) -> IntType {
     use rs::UintTy::*;
 
     let size = match uint_ty {
-        Usize => 8, // this is fixed as 8, to be compatible with BasicMemory.
+        Usize => 8, // this is fixed as 8, to be compatible with `DefaultTarget`.
         U8 => 1,
         U16 => 2,
         U32 => 4,
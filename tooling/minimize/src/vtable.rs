@@ -0,0 +1,128 @@
+use crate::*;
+
+// A pointer to a trait object is represented as a pair of a data pointer and a vtable pointer.
+// A vtable is a global following the layout of rustc's vtables:
+// drop glue, size and align of the concrete type, followed by the function pointers of the trait methods.
+
+// the type of the components of a trait object pointer.
+// Their pointees are unsized, so we don't know their layout.
+pub fn component_ptr_ty() -> Type {
+    build::raw_ptr_ty(build::layout(Size::ZERO, Align::ONE))
+}
+
+/// The type of a pointer to a trait object.
+pub fn fat_ptr_ty() -> Type {
+    let ptr_ty = component_ptr_ty();
+    let size = DefaultTarget::PTR_SIZE;
+    build::tuple_ty(
+        &[(Size::ZERO, ptr_ty), (size, ptr_ty)],
+        Size::from_bytes(size.bytes() * 2).unwrap(),
+    )
+}
+
+/// The type of a pointer to a `str`: a data pointer and the length in bytes.
+pub fn str_ptr_ty() -> Type {
+    let size = DefaultTarget::PTR_SIZE;
+    build::tuple_ty(
+        &[(Size::ZERO, component_ptr_ty()), (size, <usize as build::TypeConv>::get_type())],
+        Size::from_bytes(size.bytes() * 2).unwrap(),
+    )
+}
+
+/// Builds a trait object pointer from a thin data pointer and a vtable pointer.
+pub fn fat_ptr(data_ptr: ValueExpr, vtable_ptr: ValueExpr) -> ValueExpr {
+    let Type::Ptr(ptr_ty) = component_ptr_ty() else { unreachable!() };
+    let data_ptr = ValueExpr::UnOp {
+        operator: UnOp::Ptr2Ptr(ptr_ty),
+        operand: GcCow::new(data_ptr),
+    };
+    ValueExpr::Tuple(list![data_ptr, vtable_ptr], fat_ptr_ty())
+}
+
+/// Splits a trait object pointer into its data pointer and its vtable pointer.
+pub fn split_fat_ptr(place: PlaceExpr) -> (ValueExpr, ValueExpr) {
+    let data_ptr = build::load(build::field(place, 0));
+    let vtable_ptr = build::load(build::field(place, 1));
+    (data_ptr, vtable_ptr)
+}
+
+/// Loads the function pointer at index `idx` of a vtable.
+pub fn vtable_method(vtable_ptr: ValueExpr, idx: usize) -> ValueExpr {
+    let offset = DefaultTarget::PTR_SIZE.bytes() * Int::from(idx);
+    let slot = build::ptr_offset(vtable_ptr, build::const_int::<usize>(offset), build::InBounds::Yes);
+    let ptype = build::ptype(Type::Ptr(PtrType::FnPtr), DefaultTarget::PTR_ALIGN);
+    build::load(build::deref(slot, ptype))
+}
+
+/// Returns a pointer to the vtable of `ty` for the trait `trait_ref`.
+/// The vtable is created when it is first requested.
+pub fn vtable_ptr<'tcx>(
+    ty: rs::Ty<'tcx>,
+    trait_ref: Option<rs::PolyExistentialTraitRef<'tcx>>,
+    fcx: &mut FnCtxt<'_, 'tcx>,
+) -> ValueExpr {
+    let name = match fcx.cx.vtable_map.get(&(ty, trait_ref)) {
+        Some(name) => *name,
+        None => {
+            let name = translate_vtable(ty, trait_ref, fcx);
+            fcx.cx.vtable_map.insert((ty, trait_ref), name);
+            name
+        }
+    };
+
+    let rel = Relocation {
+        name,
+        offset: Size::ZERO,
+    };
+    ValueExpr::Constant(Constant::GlobalPointer(rel), component_ptr_ty())
+}
+
+// adds a Global representing the vtable, and returns the corresponding GlobalName.
+fn translate_vtable<'tcx>(
+    ty: rs::Ty<'tcx>,
+    trait_ref: Option<rs::PolyExistentialTraitRef<'tcx>>,
+    fcx: &mut FnCtxt<'_, 'tcx>,
+) -> GlobalName {
+    let tcx = fcx.cx.tcx;
+    let entries = match trait_ref {
+        Some(trait_ref) => {
+            let trait_ref = tcx.erase_regions(trait_ref.with_self_ty(tcx, ty));
+            tcx.vtable_entries(trait_ref)
+        }
+        None => rs::TyCtxt::COMMON_VTABLE_ENTRIES,
+    };
+    let layout = layout_of(ty, tcx);
+    let name = fresh_global_name(fcx);
+
+    let mut bytes = List::new();
+    for (i, entry) in entries.iter().enumerate() {
+        let offset = Size::from_bytes(DefaultTarget::PTR_SIZE.bytes() * Int::from(i)).unwrap();
+        let val = match entry {
+            rs::VtblEntry::MetadataSize => layout.size.bytes(),
+            rs::VtblEntry::MetadataAlign => layout.align.bytes(),
+            rs::VtblEntry::Method(instance) => {
+                // function addresses are only known at runtime, the `start` function fills this entry.
+                let fn_name = fcx.cx.fn_name(*instance);
+                fcx.cx.vtable_fns.push((Relocation { name, offset }, fn_name));
+                Int::ZERO
+            }
+            // drop glue and trait upcasting are not supported, so these entries stay null.
+            rs::VtblEntry::MetadataDropInPlace | rs::VtblEntry::Vacant | rs::VtblEntry::TraitVPtr(_) => Int::ZERO,
+        };
+        let val = DefaultTarget::ENDIANNESS
+            .encode(Unsigned, DefaultTarget::PTR_SIZE, val)
+            .unwrap();
+        for b in val {
+            bytes.push(Some(b));
+        }
+    }
+
+    let global = Global {
+        bytes,
+        relocations: List::new(),
+        align: DefaultTarget::PTR_ALIGN,
+        thread_local: false,
+    };
+    fcx.cx.globals.insert(name, global);
+    name
+}
@@ -20,5 +20,8 @@ fn cfg(path: &str) -> ui_test::Config {
 
 fn main() {
     ui_test::run_tests(cfg("./tests/pass")).unwrap();
-    ui_test::run_tests(cfg("./tests/ub")).unwrap();
+    let mut ub_cfg = cfg("./tests/ub");
+    // Allocation ids depend on everything allocated before, so they are not part of the expected output.
+    ub_cfg.stderr_filter("allocation [0-9]+", "allocation $$ID");
+    ui_test::run_tests(ub_cfg).unwrap();
 }
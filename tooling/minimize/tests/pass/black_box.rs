@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::hint::black_box;
+
+fn main() {
+    let x = black_box(41u32);
+    print(x + 1);
+    print(black_box(x == 41));
+}
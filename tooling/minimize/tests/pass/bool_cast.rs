@@ -0,0 +1,13 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn black_box<T>(t: T) -> T { t }
+
+fn main() {
+    print(black_box(true) as u8); // 1
+    print(black_box(false) as u8); // 0
+    print(black_box(true) as i32); // 1
+    print(black_box(true) as u128 + 41); // 42
+    print(black_box(3 > 2) as usize); // 1
+    print(black_box(false) as isize - 1); // -1
+}
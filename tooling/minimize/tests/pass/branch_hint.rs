@@ -0,0 +1,25 @@
+#![feature(core_intrinsics)]
+
+extern crate intrinsics;
+use intrinsics::*;
+
+use std::intrinsics::{likely, unlikely};
+
+fn classify(x: u32) -> u32 {
+    if unlikely(x == 0) {
+        return 0;
+    }
+    if likely(x < 100) {
+        1
+    } else {
+        2
+    }
+}
+
+fn main() {
+    print(classify(0));
+    print(classify(5));
+    print(classify(500));
+    print(likely(true));
+    print(unlikely(false));
+}
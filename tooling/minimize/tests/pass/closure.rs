@@ -0,0 +1,27 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn apply<F: Fn(i32) -> i32>(f: F, x: i32) -> i32 {
+    f(x)
+}
+
+fn apply_once<F: FnOnce() -> i32>(f: F) -> i32 {
+    f()
+}
+
+fn main() {
+    let x = 5;
+    let f = |y| x + y;
+    print(f(3));
+
+    let mut count = 0;
+    let mut inc = || count += 2;
+    inc();
+    inc();
+    print(count);
+
+    let z = 10;
+    let g = move |y: i32| z * y;
+    print(apply(g, 4));
+    print(apply_once(|| x + z));
+}
@@ -0,0 +1,17 @@
+//@compile-flags: --dump-fn double_sum
+
+extern crate intrinsics;
+use intrinsics::*;
+
+fn add(x: i32, y: i32) -> i32 {
+    x + y
+}
+
+fn double_sum(x: i32, y: i32) -> i32 {
+    let s = add(x, y);
+    add(s, s)
+}
+
+fn main() {
+    print(double_sum(2, 3));
+}
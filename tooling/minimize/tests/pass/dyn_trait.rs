@@ -0,0 +1,50 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+trait Shape {
+    fn area(&self) -> i32;
+
+    fn sides(&self) -> i32 {
+        4
+    }
+}
+
+struct Rect {
+    w: i32,
+    h: i32,
+}
+
+struct Triangle {
+    base: i32,
+    height: i32,
+}
+
+impl Shape for Rect {
+    fn area(&self) -> i32 {
+        self.w * self.h
+    }
+}
+
+impl Shape for Triangle {
+    fn area(&self) -> i32 {
+        self.base * self.height / 2
+    }
+
+    fn sides(&self) -> i32 {
+        3
+    }
+}
+
+fn describe(shape: &dyn Shape) {
+    print(shape.area());
+    print(shape.sides());
+}
+
+fn main() {
+    let r = Rect { w: 3, h: 4 };
+    let t = Triangle { base: 6, height: 5 };
+    let shape: &dyn Shape = &r;
+    print(shape.area());
+    describe(&r);
+    describe(&t);
+}
@@ -0,0 +1,52 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+// The flag says whether the operation failed, like `None`:
+// `?` on `Option` needs enum support, which `minimize` does not have yet.
+fn checked_div(a: i32, b: i32) -> (bool, i32) {
+    if b == 0 {
+        return (true, 0);
+    }
+    (false, a / b)
+}
+
+// What `?` expands to: return early on failure, otherwise continue with the value.
+fn div_twice(a: i32, b: i32, c: i32) -> (bool, i32) {
+    let (failed, x) = checked_div(a, b);
+    if failed {
+        return (true, 0);
+    }
+    let (failed, y) = checked_div(x, c);
+    if failed {
+        return (true, 0);
+    }
+    (false, y + 1)
+}
+
+// An early return from inside a loop.
+fn first_multiple_of(n: i32, limit: i32) -> i32 {
+    let mut i = 1;
+    while i < limit {
+        if i % n == 0 {
+            return i;
+        }
+        i += 1;
+    }
+    -1
+}
+
+fn main() {
+    let (failed, v) = div_twice(100, 5, 2);
+    print(failed);
+    print(v);
+
+    let (failed, v) = div_twice(100, 0, 2);
+    print(failed);
+    print(v);
+
+    let (failed, _) = div_twice(100, 5, 0);
+    print(failed);
+
+    print(first_multiple_of(7, 20));
+    print(first_multiple_of(7, 5));
+}
@@ -0,0 +1,10 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let x = 42u32;
+    // the integer-to-pointer cast picks up the provenance exposed by the pointer-to-integer cast.
+    let addr = &x as *const u32 as usize;
+    let ptr = addr as *const u32;
+    print(unsafe { *ptr });
+}
@@ -0,0 +1,28 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn black_box<T>(t: T) -> T { t }
+
+fn main() {
+    // Sign-extend, truncate, zero-extend.
+    let x = black_box(-3i8);
+    print(x as i64 as u16 as usize); // 65533
+
+    // Widening from signed and unsigned sources.
+    print(black_box(-1i16) as i128); // -1
+    print(black_box(-1i16) as u128); // 340282366920938463463374607431768211455
+    print(black_box(u64::MAX) as i128); // 18446744073709551615
+    print(black_box(-1i32) as usize); // 18446744073709551615
+    print(black_box(u8::MAX) as isize); // 255
+
+    // Narrowing keeps the low bits.
+    print(black_box(u128::MAX) as i8); // -1
+    print(black_box(0x1_0000_0001i64) as u32); // 1
+    print(black_box(-129isize) as i8); // 127
+    print(black_box(70000u32) as i16); // 4464
+    print(black_box(i128::MIN) as u64); // 0
+
+    // Same width, different signedness.
+    print(black_box(i64::MIN) as u64); // 9223372036854775808
+    print(black_box(usize::MAX) as isize); // -1
+}
@@ -0,0 +1,12 @@
+//@compile-flags: --list-fns
+
+extern crate intrinsics;
+use intrinsics::*;
+
+fn add(x: i32, y: i32) -> i32 {
+    x + y
+}
+
+fn main() {
+    print(add(2, 3));
+}
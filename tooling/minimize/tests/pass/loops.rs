@@ -0,0 +1,47 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    // `loop` with `break` and `continue`: sums up the odd numbers below 10.
+    let mut i = 0;
+    let mut sum = 0;
+    loop {
+        i += 1;
+        if i >= 10 {
+            break;
+        }
+        if i % 2 == 0 {
+            continue;
+        }
+        sum += i;
+    }
+    print(sum);
+
+    // a labeled `break` out of a nested loop: finds the first pair with `x * y == 12`.
+    let mut found = 0;
+    let mut x = 0;
+    'outer: while x < 4 {
+        x += 1;
+        let mut y = 1;
+        while y < 10 {
+            if x * y == 12 {
+                found = x * 10 + y;
+                break 'outer;
+            }
+            y += 1;
+        }
+        // only reached for `x == 1`, since the inner loop breaks out of both loops for `x == 2`.
+        print(x);
+    }
+    print(found);
+
+    // `break` with a value.
+    let mut n = 1;
+    let v = loop {
+        n *= 2;
+        if n > 50 {
+            break n;
+        }
+    };
+    print(v);
+}
@@ -0,0 +1,35 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[derive(Clone, Copy)]
+struct Inner {
+    a: u8,
+    c: u32,
+}
+
+struct Middle {
+    tag: u16,
+    items: [Inner; 3],
+}
+
+struct Outer {
+    x: u64,
+    m: Middle,
+}
+
+fn read(ptr: &Outer, i: usize) -> u32 {
+    (*ptr).m.items[i].c
+}
+
+fn main() {
+    let inner = Inner { a: 1, c: 10 };
+    let mut o = Outer { x: 7, m: Middle { tag: 3, items: [inner; 3] } };
+    o.m.items[1].c = 20;
+    o.m.items[2] = Inner { a: 2, c: 30 };
+    print(read(&o, 1));
+    print(read(&o, 2));
+    let r = &mut o.m;
+    r.items[0].c = 5;
+    print(o.m.items[0].c + o.m.items[0].a as u32);
+    print(o.x + o.m.tag as u64 + o.m.items[2].a as u64);
+}
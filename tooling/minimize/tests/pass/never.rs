@@ -0,0 +1,22 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn die() -> ! {
+    print(1);
+    exit();
+    loop {}
+}
+
+fn pick(x: u32) -> u32 {
+    // the second arm produces `!`, so the call to `die` does not return.
+    match x == 0 {
+        true => 10,
+        false => die(),
+    }
+}
+
+fn main() {
+    print(pick(0));
+    print(pick(1));
+    print(2);
+}
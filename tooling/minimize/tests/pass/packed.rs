@@ -0,0 +1,31 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+#[repr(C, packed)]
+struct Packed {
+    a: u8,
+    b: u32,
+}
+
+#[repr(C, packed(2))]
+struct Packed2 {
+    a: u8,
+    b: u32,
+}
+
+fn read_b(p: &Packed) -> u32 {
+    // The place `p.b` has alignment 1, so this load is fine even though `b` is at offset 1.
+    p.b
+}
+
+fn main() {
+    let p = Packed { a: 1, b: 42 };
+    print(read_b(&p));
+
+    // `b` is at offset 2, which is enough for the reduced alignment of 2.
+    let q = Packed2 { a: 2, b: 7 };
+    let b = q.b;
+    print(b);
+
+    print(p.a + q.a);
+}
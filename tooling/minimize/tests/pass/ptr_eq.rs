@@ -0,0 +1,11 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let arr = [1u8, 2];
+    let p = &arr[0] as *const u8;
+    let q = &arr[1] as *const u8;
+    print(p == p);
+    print(p == q);
+    print(p != q);
+}
@@ -0,0 +1,12 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+static OTHER: i32 = 5;
+static REF: &i32 = &OTHER;
+const SCALE: i32 = 3;
+
+fn main() {
+    print(OTHER);
+    print(*REF * SCALE);
+    print(SCALE + 1);
+}
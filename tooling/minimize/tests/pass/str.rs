@@ -0,0 +1,19 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let s: &str = "hi";
+    print(s);
+    let p = s as *const str as *const u8;
+    unsafe {
+        print(*p);
+        print(*p.add(1));
+    }
+
+    // `add` counts elements, not bytes.
+    let a = [10i32, 20, 30];
+    let q = &a as *const [i32; 3] as *const i32;
+    unsafe {
+        print(*q.add(2));
+    }
+}
@@ -0,0 +1,48 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+trait Shape {
+    fn area(&self) -> i32;
+
+    fn double_area(&self) -> i32 {
+        self.area() * 2
+    }
+}
+
+struct Rect {
+    w: i32,
+    h: i32,
+}
+
+struct Square(i32);
+
+impl Shape for Rect {
+    fn area(&self) -> i32 {
+        self.w * self.h
+    }
+}
+
+impl Shape for Square {
+    fn area(&self) -> i32 {
+        self.0 * self.0
+    }
+
+    fn double_area(&self) -> i32 {
+        self.0 * self.0 + self.0 * self.0
+    }
+}
+
+fn area_of<S: Shape>(s: &S) -> i32 {
+    s.area()
+}
+
+fn main() {
+    let r = Rect { w: 3, h: 4 };
+    let s = Square(5);
+    print(r.area());
+    print(s.area());
+    print(r.double_area());
+    print(s.double_area());
+    print(area_of(&r));
+    print(Shape::area(&s));
+}
@@ -0,0 +1,29 @@
+//@compile-flags: --dump-fn call_area
+
+extern crate intrinsics;
+use intrinsics::*;
+
+trait Shape {
+    fn area(&self) -> i32;
+}
+
+struct Rect {
+    w: i32,
+    h: i32,
+}
+
+impl Shape for Rect {
+    fn area(&self) -> i32 {
+        self.w * self.h
+    }
+}
+
+// The call is lowered to a direct call of `<Rect as Shape>::area`,
+// which takes `&self` as a pointer to a `Rect`.
+fn call_area(r: &Rect) -> i32 {
+    r.area()
+}
+
+fn main() {
+    print(call_area(&Rect { w: 3, h: 4 }));
+}
@@ -0,0 +1,13 @@
+extern crate intrinsics;
+use intrinsics::*;
+
+fn main() {
+    let bytes: [u8; 4] = unsafe { std::mem::transmute(0x01020304u32) };
+    print(bytes[0]);
+    print(bytes[1]);
+    print(bytes[2]);
+    print(bytes[3]);
+
+    let x: i32 = unsafe { std::mem::transmute(u32::MAX) };
+    print(x);
+}
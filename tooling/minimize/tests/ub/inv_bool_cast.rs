@@ -0,0 +1,6 @@
+include!("../helper/transmute.rs");
+
+fn main() { unsafe {
+    let b = transmute::<u8, bool>(3);
+    let _x = b as u8;
+} }
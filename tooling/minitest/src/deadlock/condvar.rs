@@ -0,0 +1,22 @@
+use crate::*;
+
+#[test]
+fn wait_without_notify() {
+    // The main function waits on a condvar that nobody ever notifies.
+    // A waiting thread is not runnable, so this deadlocks.
+
+    let b0 = block!( create_lock(global::<u32>(0), 1) );
+    let b1 = block!( create_condvar(global::<u32>(1), 2) );
+    let b2 = block!( acquire(load(global::<u32>(0)), 3) );
+    let b3 = block!( condvar_wait(load(global::<u32>(1)), load(global::<u32>(0)), 4) );
+    let b4 = block!( release(load(global::<u32>(0)), 5) );
+    let b5 = block!( exit() );
+    let main = function(Ret::No, 0, &[], &[b0, b1, b2, b3, b4, b5]);
+
+    // global(0) stores the lock id, global(1) the condvar id.
+    let globals = [global_int::<u32>(), global_int::<u32>()];
+
+    let p = program_with_globals(&[main], &globals);
+
+    assert_deadlock(p);
+}
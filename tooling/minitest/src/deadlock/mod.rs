@@ -1 +1,3 @@
 mod join_lock;
+mod rwlock;
+mod condvar;
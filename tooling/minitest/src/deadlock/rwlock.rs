@@ -0,0 +1,32 @@
+use crate::*;
+
+#[test]
+fn rwlock_writer_waits_for_reader() {
+    // The main function holds the rwlock for reading and waits for a thread that wants to write.
+
+    // The locals are used to store the thread ids.
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!( create_rwlock(global::<u32>(0), 1) );
+    let b1 = block!( acquire_read(load(global::<u32>(0)), 2) );
+    let b2 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 3)
+    );
+    let b3 = block!( join(load(local(0)), 4) );
+    let b4 = block!( release_read(load(global::<u32>(0)), 5) );
+    let b5 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    let b0 = block!( acquire_write(load(global::<u32>(0)), 1) );
+    let b1 = block!( release_write(load(global::<u32>(0)), 2) );
+    let b2 = block!( return_() );
+    let second = function(Ret::No, 0, &[], &[b0, b1, b2]);
+
+    // global(0) is used as a rwlock. We store the rwlock id there.
+    let globals = [global_int::<u32>()];
+
+    let p = program_with_globals(&[main, second], &globals);
+
+    assert_deadlock(p);
+}
@@ -0,0 +1,11 @@
+use crate::*;
+
+#[test]
+fn abs_unsigned() {
+    let locals = [<u8>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), abs::<u8>(const_int::<u8>(1))),
+    ];
+    assert_ill_formed_msg(small_program(&locals, &stmts), "absolute value of an unsigned integer type");
+}
@@ -0,0 +1,10 @@
+use crate::*;
+
+#[test]
+fn assert_message_missing() {
+    let b0 = block!( assert(const_bool(true), 0, 1) );
+    let b1 = block!( exit() );
+    let main = function(Ret::No, 0, &[], &[b0, b1]);
+
+    assert_ill_formed_msg(program(&[main]), "assertion message refers to non-existing global");
+}
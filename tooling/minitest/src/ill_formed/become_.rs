@@ -0,0 +1,35 @@
+use crate::*;
+
+fn other_f() -> Function {
+    let locals = locals!(ret: (), arg: ());
+    let b0 = block!(exit());
+
+    function(Ret::Yes, 1, &locals, &[b0])
+}
+
+#[test]
+fn static_become_arg_count() {
+    let locals = locals!(x: ());
+    let b0 = block!( become_call(fn_ptr(1), &[]) );
+
+    let f = function(Ret::No, 0, &locals, &[b0]);
+    let p = program(&[f, other_f()]);
+    dump_program(p);
+    assert_ill_formed_msg(p, "tail call ABI mismatch: number of arguments does not agree");
+}
+
+#[test]
+fn static_become_arg_abi() {
+    let locals = locals!(x: ());
+    let b0 = block!(
+        Terminator::Become {
+            callee: fn_ptr(1),
+            arguments: list![(const_unit(), ArgAbi::Stack(Size::ZERO, Align::ONE))],
+        }
+    );
+
+    let f = function(Ret::No, 0, &locals, &[b0]);
+    let p = program(&[f, other_f()]);
+    dump_program(p);
+    assert_ill_formed_msg(p, "tail call ABI mismatch: argument ABI does not agree");
+}
@@ -0,0 +1,11 @@
+use crate::*;
+
+#[test]
+fn bool_to_int_non_bool() {
+    let locals = [<u8>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), bool_to_int::<u8>(const_int::<u8>(1))),
+    ];
+    assert_ill_formed_msg(small_program(&locals, &stmts), "boolean-to-integer cast of non-boolean operand");
+}
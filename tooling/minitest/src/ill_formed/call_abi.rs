@@ -0,0 +1,78 @@
+use crate::*;
+
+fn other_f() -> Function {
+    let locals = [<()>::get_ptype(); 2];
+    let b0 = block!(exit());
+
+    function(Ret::Yes, 1, &locals, &[b0])
+}
+
+fn other_arg_abi() -> ArgAbi {
+    ArgAbi::Stack(Size::ZERO, Align::ONE)
+}
+
+#[test]
+fn static_call_arg_count() {
+    let locals = [<()>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        Terminator::Call {
+            callee: fn_ptr(1),
+            arguments: list![],
+            ret: Some((local(0), ArgAbi::Register)),
+            next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
+        }
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f, other_f()]);
+    dump_program(p);
+    assert_ill_formed_msg(p, "call ABI mismatch: number of arguments does not agree");
+}
+
+#[test]
+fn static_call_arg_abi() {
+    let locals = [<()>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        Terminator::Call {
+            callee: fn_ptr(1),
+            arguments: list![(const_unit(), other_arg_abi())],
+            ret: Some((local(0), ArgAbi::Register)),
+            next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
+        }
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f, other_f()]);
+    dump_program(p);
+    assert_ill_formed_msg(p, "call ABI mismatch: argument ABI does not agree");
+}
+
+#[test]
+fn static_call_ret_abi() {
+    let locals = [<()>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        Terminator::Call {
+            callee: fn_ptr(1),
+            arguments: list![(const_unit(), ArgAbi::Register)],
+            ret: Some((local(0), other_arg_abi())),
+            next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
+        }
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f, other_f()]);
+    dump_program(p);
+    assert_ill_formed_msg(p, "call ABI mismatch: return ABI does not agree");
+}
@@ -0,0 +1,72 @@
+use crate::*;
+
+fn wf_error(p: Program) -> std::string::String {
+    fmt_wf_error(check_program(p).unwrap_err())
+}
+
+#[test]
+fn use_before_live() {
+    let locals = [<i32>::get_ptype()];
+    let stmts = [assign(local(0), const_int::<i32>(1))];
+    let p = small_program(&locals, &stmts);
+    assert_eq!(wf_error(p), "f0/bb0/stmt0: local _0 is used but not live");
+}
+
+#[test]
+fn live_undeclared_local() {
+    let locals = [<i32>::get_ptype()];
+    let stmts = [storage_live(0), storage_live(3)];
+    let p = small_program(&locals, &stmts);
+    assert_eq!(wf_error(p), "f0/bb0/stmt1: local _3 is not declared");
+}
+
+#[test]
+fn assign_type_mismatch() {
+    let locals = [<i32>::get_ptype()];
+    let stmts = [storage_live(0), assign(local(0), const_bool(true))];
+    let p = small_program(&locals, &stmts);
+    assert_eq!(wf_error(p), "f0/bb0/stmt1: assignment source and destination have different types");
+}
+
+#[test]
+fn non_bool_condition() {
+    let b0 = block!(if_(const_int::<u8>(1), 1, 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    let p = program(&[f]);
+    assert_eq!(wf_error(p), "f0/bb0/terminator: `if` condition is not a boolean");
+}
+
+#[test]
+fn unreachable_block() {
+    let b0 = block!(exit());
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    let p = program(&[f]);
+    assert_eq!(wf_error(p), "f0/bb1: block is unreachable");
+}
+
+#[test]
+fn thread_local_relocation() {
+    let mut pointer = global_int::<usize>();
+    pointer.relocations = list![(size(0), Relocation { name: GlobalName(Name::from_internal(1)), offset: size(0) })];
+    let globals = [pointer, thread_local_int::<u32>()];
+
+    let b0 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program_with_globals(&[f], &globals);
+    assert_eq!(wf_error(p), "global(0): relocation to a thread-local global");
+}
+
+#[test]
+fn no_start_function() {
+    let p = program(&[]);
+    assert_eq!(wf_error(p), "start function does not exist");
+}
+
+#[test]
+fn run_reports_wf_error() {
+    let p = program(&[]);
+    let TerminationInfo::IllFormed(err) = run_program(p) else { panic!() };
+    assert_eq!(fmt_wf_error(err), "start function does not exist");
+}
@@ -0,0 +1,13 @@
+use crate::*;
+
+#[test]
+fn diverging_call_to_returning_fn() {
+    let b0 = block!( call_diverging(1, &[]) );
+    let main = function(Ret::No, 0, &[], &[b0]);
+
+    let locals = [<()>::get_ptype()];
+    let b0 = block!( return_() );
+    let f = function(Ret::Yes, 0, &locals, &[b0]);
+
+    assert_ill_formed_msg(program(&[main, f]), "diverging call to a function that can return");
+}
@@ -5,5 +5,5 @@ fn double_live() {
     let locals = vec![ <bool>::get_ptype() ];
     let stmts = vec![storage_live(0), storage_live(0)];
     let p = small_program(&locals, &stmts);
-    assert_ill_formed(p);
+    assert_ill_formed_msg(p, "local _0 is already live");
 }
@@ -0,0 +1,122 @@
+use crate::*;
+
+/// Assigns `val` to a local of type `ty`.
+fn assign_enum(ty: Type, val: ValueExpr) -> Program {
+    let locals = [ptype(ty, align(1))];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), val),
+        exit()
+    );
+    let f = function(Ret::No, 0, &locals, &[b0]);
+    program(&[f])
+}
+
+#[test]
+fn variant_does_not_exist() {
+    let ty = enum_ty::<u8>(&[<()>::get_type()], size(0), size(1));
+    let p = assign_enum(ty, variant(1, const_unit(), ty));
+    assert_ill_formed_msg(p, "variant expression refers to non-existing variant");
+}
+
+#[test]
+fn variant_data_wrong_type() {
+    let ty = enum_ty::<u8>(&[<u8>::get_type()], size(1), size(2));
+    let p = assign_enum(ty, variant(0, const_bool(true), ty));
+    assert_ill_formed_msg(p, "variant data expression has the wrong type");
+}
+
+#[test]
+fn tag_out_of_bounds() {
+    let ty = enum_ty::<u16>(&[<()>::get_type()], size(0), size(1));
+    let p = assign_enum(ty, variant(0, const_unit(), ty));
+    assert_ill_formed_msg(p, "enum tag exceeds the enum size");
+}
+
+#[test]
+fn tag_overlaps_data() {
+    let ty = enum_ty::<u8>(&[<()>::get_type(), <u16>::get_type()], size(1), size(2));
+    let p = assign_enum(ty, variant(0, const_unit(), ty));
+    assert_ill_formed_msg(p, "enum tag overlaps with the data of a variant");
+}
+
+#[test]
+fn tag_overlaps_array_element() {
+    let ty = enum_ty::<u8>(&[<[u8; 2]>::get_type()], size(1), size(3));
+    let p = assign_enum(ty, variant(0, const_array(&[const_int::<u8>(0); 2], <u8>::get_type()), ty));
+    assert_ill_formed_msg(p, "enum tag overlaps with the data of a variant");
+}
+
+/// The tag may go between the fields of a tuple.
+#[test]
+fn tag_in_tuple_padding() {
+    let data_ty = tuple_ty(&[(size(0), <u8>::get_type()), (size(2), <u16>::get_type())], size(4));
+    let ty = enum_ty::<u8>(&[data_ty], size(1), size(4));
+    let data = const_tuple(&[const_int::<u8>(1), const_int::<u16>(2)], data_ty);
+    let p = assign_enum(ty, variant(0, data, ty));
+    assert!(check_program(p).is_ok());
+}
+
+#[test]
+fn discriminant_of_non_enum() {
+    let locals = [<u8>::get_ptype(), <u8>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), discriminant::<u8>(local(0))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ill_formed_msg(p, "discriminant read from a place of non-enum type");
+}
+
+#[test]
+fn niche_value_missing() {
+    // Variant 0 is untagged, but variant 2 has no niche value.
+    let variants = [<bool>::get_type(), <()>::get_type(), <()>::get_type()];
+    let ty = niche_enum_ty::<u8>(&variants, 0, size(0), &[(1, 2)], size(1));
+    let p = assign_enum(ty, variant(1, const_unit(), ty));
+    assert_ill_formed_msg(p, "enum niche values do not match the variants");
+}
+
+#[test]
+fn niche_value_duplicate() {
+    let variants = [<bool>::get_type(), <()>::get_type(), <()>::get_type()];
+    let ty = niche_enum_ty::<u8>(&variants, 0, size(0), &[(1, 2), (2, 2)], size(1));
+    let p = assign_enum(ty, variant(1, const_unit(), ty));
+    assert_ill_formed_msg(p, "enum niche value is used for multiple variants");
+}
+
+#[test]
+fn niche_value_is_valid() {
+    // `true` is a valid `bool`, so it cannot encode variant 1.
+    let variants = [<bool>::get_type(), <()>::get_type()];
+    let ty = niche_enum_ty::<u8>(&variants, 0, size(0), &[(1, 1)], size(1));
+    let p = assign_enum(ty, variant(1, const_unit(), ty));
+    assert_ill_formed_msg(p, "enum niche value is valid for the untagged variant");
+}
+
+#[test]
+fn niche_in_int() {
+    // Every value of a `u8` is valid, so it has no niche.
+    let variants = [<u8>::get_type(), <()>::get_type()];
+    let ty = niche_enum_ty::<u8>(&variants, 0, size(0), &[(1, 2)], size(1));
+    let p = assign_enum(ty, variant(1, const_unit(), ty));
+    assert_ill_formed_msg(p, "enum niche value is valid for the untagged variant");
+}
+
+#[test]
+fn niche_not_a_scalar() {
+    // The tag straddles both fields of the untagged variant.
+    let data_ty = tuple_ty(&[(size(0), <bool>::get_type()), (size(1), <bool>::get_type())], size(2));
+    let ty = niche_enum_ty::<u16>(&[data_ty, <()>::get_type()], 0, size(0), &[(1, 2)], size(2));
+    let p = assign_enum(ty, variant(1, const_unit(), ty));
+    assert_ill_formed_msg(p, "enum niche is not a scalar field of the untagged variant");
+}
+
+#[test]
+fn niche_overlaps_tagged_variant() {
+    let variants = [<bool>::get_type(), <u8>::get_type()];
+    let ty = niche_enum_ty::<u8>(&variants, 0, size(0), &[(1, 2)], size(1));
+    let p = assign_enum(ty, variant(0, const_bool(true), ty));
+    assert_ill_formed_msg(p, "enum tag overlaps with the data of a variant");
+}
@@ -4,3 +4,14 @@ mod double_live;
 mod type_mismatch;
 mod no_main;
 mod neg_count_array;
+mod transmute_size;
+mod thread_local_relocation;
+mod diagnostics;
+mod enum_variant;
+mod call_abi;
+mod abs_unsigned;
+mod offset_from_zst;
+mod diverging_call;
+mod become_;
+mod bool_to_int;
+mod assert;
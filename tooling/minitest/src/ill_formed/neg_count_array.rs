@@ -12,5 +12,5 @@ fn neg_count_array() {
 
     let p = small_program(locals, stmts);
     dump_program(p);
-    assert_ill_formed(p);
+    assert_ill_formed_msg(p, "array has negative length");
 }
@@ -3,5 +3,5 @@ use crate::*;
 #[test]
 fn no_main() {
     let p = program(&[]);
-    assert_ill_formed(p);
+    assert_ill_formed_msg(p, "start function does not exist");
 }
@@ -0,0 +1,13 @@
+use crate::*;
+
+#[test]
+fn offset_from_zst() {
+    let locals = [<()>::get_ptype(), <isize>::get_ptype()];
+    let ptr = addr_of(local(0), <*const ()>::get_type());
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), offset_from(ptr, ptr)),
+    ];
+    assert_ill_formed_msg(small_program(&locals, &stmts), "pointer difference with zero-sized pointee");
+}
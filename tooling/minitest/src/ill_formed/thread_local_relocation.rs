@@ -0,0 +1,13 @@
+use crate::*;
+
+#[test]
+fn thread_local_relocation() {
+    let mut pointer = global_int::<usize>();
+    pointer.relocations = list![(size(0), Relocation { name: GlobalName(Name::from_internal(1)), offset: size(0) })];
+    let globals = [pointer, thread_local_int::<u32>()];
+
+    let b0 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program_with_globals(&[f], &globals);
+    assert_ill_formed_msg(p, "relocation to a thread-local global");
+}
@@ -9,5 +9,5 @@ fn too_large_local() {
     let stmts = &[];
 
     let prog = small_program(locals, stmts);
-    assert_ill_formed(prog);
+    assert_ill_formed_msg(prog, "type size is too big");
 }
@@ -0,0 +1,12 @@
+use crate::*;
+
+#[test]
+fn transmute_size_mismatch() {
+    let locals = [<u16>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), transmute(const_int::<u32>(0), <u16>::get_type())),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ill_formed_msg(p, "transmute between types of different size");
+}
@@ -11,5 +11,5 @@ fn type_mismatch() {
         ),
     ];
     let p = small_program(locals, stmts);
-    assert_ill_formed(p);
+    assert_ill_formed_msg(p, "assignment source and destination have different types");
 }
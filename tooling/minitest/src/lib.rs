@@ -22,15 +22,91 @@ mod ill_formed;
 mod deadlock;
 
 pub fn assert_stop(prog: Program) {
-    assert_eq!(run_program(prog), TerminationInfo::MachineStop);
+    assert_eq!(run_program(prog), TerminationInfo::MachineStop { code: Int::ZERO });
+}
+
+pub fn assert_exit_code(prog: Program, code: i32) {
+    match run_program(prog) {
+        TerminationInfo::MachineStop { code: c } if c == Int::from(code) => {},
+        TerminationInfo::MachineStop { code: c } => panic!("program exited with code {c}, expected {code}"),
+        termination_info => panic!("program did not exit with code {code}: {termination_info:?}"),
+    }
+}
+
+pub fn assert_abort(prog: Program) {
+    assert_eq!(run_program(prog), TerminationInfo::Abort);
+}
+
+/// Run the program and check that it printed exactly the `expected` lines to stdout.
+pub fn assert_stdout(prog: Program, expected: &[&str]) {
+    assert_stdout_on::<DefaultTarget>(prog, expected)
+}
+
+/// Like `assert_stdout`, but run the program on the target `T`.
+pub fn assert_stdout_on<T: Target>(prog: Program, expected: &[&str]) {
+    match get_stdout_on::<T>(prog) {
+        Ok(out) => assert_lines("stdout", out, expected),
+        Err(termination_info) => panic!("program did not terminate correctly: {termination_info:?}"),
+    }
+}
+
+/// Run the program and check that it printed exactly the `expected` lines to stderr.
+pub fn assert_stderr(prog: Program, expected: &[&str]) {
+    match get_stderr(prog) {
+        Ok(out) => assert_lines("stderr", out, expected),
+        Err(termination_info) => panic!("program did not terminate correctly: {termination_info:?}"),
+    }
+}
+
+fn assert_lines(stream: &str, mut out: Vec<String>, expected: &[&str]) {
+    // Every printed line ends in a newline, so the last "line" is empty.
+    if out.last().is_some_and(|l| l.is_empty()) {
+        out.pop();
+    }
+    if out == expected {
+        return;
+    }
+
+    // Show the lines side by side, marking the ones that differ.
+    let mut diff = String::new();
+    for i in 0..out.len().max(expected.len()) {
+        let actual = out.get(i).map(|l| l.as_str());
+        let exp = expected.get(i).copied();
+        let marker = if actual == exp { ' ' } else { '!' };
+        diff += &format!("{marker} {:>3}: {:<20} | {}\n", i, actual.unwrap_or("<missing>"), exp.unwrap_or("<missing>"));
+    }
+    panic!("{stream} does not match the expected output\n       actual               | expected\n{diff}");
 }
 
 pub fn assert_ub(prog: Program, msg: &str) {
     assert_eq!(run_program(prog), TerminationInfo::Ub(minirust_rs::prelude::String::from_internal(msg.to_string())));
 }
 
+/// Like `assert_ub`, but only check that the message starts with `prefix`.
+/// Use this for messages that contain details like allocation ids, which change whenever
+/// anything else is allocated first.
+pub fn assert_ub_prefix(prog: Program, prefix: &str) {
+    match run_program(prog) {
+        TerminationInfo::Ub(msg) => assert!(msg.get_internal().starts_with(prefix), "UB for a different reason: {msg}"),
+        info => panic!("program did not raise UB: {info:?}"),
+    }
+}
+
+/// Like `assert_ub`, but with the aliasing checks of the memory model enabled.
+pub fn assert_aliasing_ub(prog: Program, msg: &str) {
+    assert_eq!(run_program_with_aliasing_checks(prog), TerminationInfo::Ub(minirust_rs::prelude::String::from_internal(msg.to_string())));
+}
+
 pub fn assert_ill_formed(prog: Program) {
-    assert_eq!(run_program(prog), TerminationInfo::IllFormed);
+    assert!(matches!(run_program(prog), TerminationInfo::IllFormed(_)));
+}
+
+/// Check that the program is ill-formed because of the well-formedness requirement `msg`.
+pub fn assert_ill_formed_msg(prog: Program, msg: &str) {
+    match run_program(prog) {
+        TerminationInfo::IllFormed(err) => assert_eq!(err.msg.get_internal(), msg, "program is ill-formed for a different reason"),
+        info => panic!("program is not ill-formed: {info:?}"),
+    }
 }
 
 pub fn assert_deadlock(prog: Program) {
@@ -41,14 +117,18 @@ pub fn assert_deadlock(prog: Program) {
 /// Run the program multiple times. Checks if we get a data race in some execution
 /// This automatically fails if the program does not terminate correctly if the data race did not occur.
 pub fn has_data_race(prog: Program) -> bool {
-    let data_race_string = minirust_rs::prelude::String::from_internal("Data race".to_string());
+    find_data_race(prog).is_some()
+}
 
+/// Like `has_data_race`, but returns the UB message describing the first data race found.
+pub fn find_data_race(prog: Program) -> Option<String> {
     for _ in 0..20 {
         match run_program(prog) {
-            TerminationInfo::MachineStop => {},
+            TerminationInfo::MachineStop { .. } => {},
             TerminationInfo::Ub(ub) => {
-                if ub == data_race_string {
-                    return true;
+                let msg = ub.get_internal();
+                if msg.starts_with("Data race") {
+                    return Some(msg);
                 }
                 panic!("Non data race undefined behavior");
             },
@@ -56,5 +136,5 @@ pub fn has_data_race(prog: Program) -> bool {
         }
     }
 
-    false
+    None
 }
@@ -0,0 +1,11 @@
+use crate::*;
+
+#[test]
+fn abort_terminates() {
+    let b0 = block!(abort());
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program(&[f]);
+    dump_program(p);
+    assert_abort(p);
+}
+
@@ -0,0 +1,12 @@
+use crate::*;
+
+#[test]
+fn abs_signed() {
+    let b0 = block!( print(abs::<i32>(const_int::<i32>(-42)), 1) );
+    let b1 = block!( print(abs::<i8>(const_int::<i8>(i8::MAX)), 2) );
+    let b2 = block!( print(abs::<i8>(const_int::<i8>(-i8::MAX)), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2, b3]);
+    assert_stdout(program(&[f]), &["42", "127", "127"]);
+}
@@ -0,0 +1,43 @@
+use crate::*;
+
+/// Local 0 is a `(u8, u32)`, local 1 a pointer to it. Writes `42` through
+/// `&raw mut (*ptr).1` and prints the field directly.
+#[test]
+fn write_through_field_address() {
+    let locals = [<(u8, u32)>::get_ptype(), <*mut (u8, u32)>::get_ptype(), <*mut u32>::get_ptype()];
+    let root = || deref(load(local(1)), <(u8, u32)>::get_ptype());
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(1), addr_of(local(0), <*mut (u8, u32)>::get_type())),
+        // The field pointer forgets that it was derived from a `deref`.
+        assign(local(2), addr_of_field(root(), 1, <*mut u32>::get_type())),
+        assign(deref(load(local(2)), <u32>::get_ptype()), const_int::<u32>(42)),
+        print(load(field(local(0), 1)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_stdout(program(&[f]), &["42"]);
+}
+
+/// Writes `7` through `&raw mut (*ptr)[2]` of a `[u16; 4]` and prints the element directly.
+#[test]
+fn write_through_index_address() {
+    let locals = [<[u16; 4]>::get_ptype(), <*mut [u16; 4]>::get_ptype()];
+    let root = || deref(load(local(1)), <[u16; 4]>::get_ptype());
+    let elem_ptr = addr_of_index(root(), const_int::<usize>(2), <*mut u16>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_array(&[const_int::<u16>(0); 4], <u16>::get_type())),
+        assign(local(1), addr_of(local(0), <*mut [u16; 4]>::get_type())),
+        assign(deref(elem_ptr, <u16>::get_ptype()), const_int::<u16>(7)),
+        print(load(index(local(0), const_int::<usize>(2))), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_stdout(program(&[f]), &["7"]);
+}
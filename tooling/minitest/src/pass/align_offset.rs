@@ -0,0 +1,47 @@
+use crate::*;
+
+/// Allocates 32 bytes aligned to 16, so the address of the allocation is a multiple of 16,
+/// and prints the `align_offset` of a `*const T` pointing `offset` bytes into it.
+fn align_offset_of<T: TypeConv>(offset: usize, align: usize) -> Program {
+    let locals = [<*const T>::get_ptype(), <usize>::get_ptype()];
+    let size = const_int::<usize>(32);
+    let base_align = const_int::<usize>(16);
+    let ptr = offset_inbounds(load(local(0)), const_int::<usize>(offset));
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        allocate(size, base_align, local(0), 1),
+    );
+    let b1 = block!( align_offset(ptr, const_int::<usize>(align), local(1), 2) );
+    let b2 = block!( print(load(local(1)), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn already_aligned() {
+    assert_stdout(align_offset_of::<u8>(8, 8), &["0"]);
+}
+
+#[test]
+fn bytes() {
+    let p = align_offset_of::<u8>(1, 8);
+    assert!(fmt_program(p).contains("align_offset("));
+    assert_stdout(p, &["7"]);
+}
+
+#[test]
+fn counts_elements() {
+    assert_stdout(align_offset_of::<u16>(2, 8), &["3"]);
+    // With 3-byte elements, `1 + 3 * 5 == 16`.
+    assert_stdout(align_offset_of::<[u8; 3]>(1, 16), &["5"]);
+}
+
+#[test]
+fn impossible() {
+    let usize_max = u64::MAX.to_string();
+    assert_stdout(align_offset_of::<u16>(1, 4), &[&usize_max]);
+    assert_stdout(align_offset_of::<()>(1, 4), &[&usize_max]);
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Reads a `u32` at `offset` bytes into a `u32`-aligned buffer of 8 bytes.
+fn read_at_offset(offset: u64) -> Program {
+    let locals = [<[u32; 2]>::get_ptype(), <u32>::get_ptype()];
+    let ptr = ptr_offset(addr_of(local(0), <*const u8>::get_type()), const_int::<usize>(offset), InBounds::No);
+
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_array(&[const_int::<u32>(0); 2], <u32>::get_type())),
+        assign(local(1), load(deref(ptr, <u32>::get_ptype()))),
+    ];
+    small_program(&locals, &stmts)
+}
+
+fn without_alignment_check() -> RunConfig {
+    RunConfig { check_alignment: false, ..RunConfig::default() }
+}
+
+#[test]
+fn misaligned_read() {
+    assert_ub(read_at_offset(1), "pointer is insufficiently aligned");
+    assert_eq!(run_program_with_config(read_at_offset(1), without_alignment_check()), TerminationInfo::MachineStop { code: Int::ZERO });
+}
+
+/// Disabling the alignment check keeps the bounds check.
+#[test]
+fn misaligned_out_of_bounds_read() {
+    assert_eq!(
+        run_program_with_config(read_at_offset(5), without_alignment_check()),
+        TerminationInfo::Ub(minirust_rs::prelude::String::from_internal("out-of-bounds memory access".to_string())),
+    );
+}
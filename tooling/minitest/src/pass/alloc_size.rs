@@ -0,0 +1,21 @@
+use crate::*;
+
+#[test]
+fn alloc_size_16() {
+    let locals = [<*const u8>::get_ptype(), <usize>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        allocate(const_int::<usize>(16), const_int::<usize>(8), local(0), 1),
+    );
+    let b1 = block!( alloc_size(load(local(0)), local(1), 2) );
+    let b2 = block!( print(load(local(1)), 3) );
+    let b3 = block!( deallocate(load(local(0)), const_int::<usize>(16), const_int::<usize>(8), 4) );
+    let b4 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    let p = program(&[f]);
+    dump_program(p);
+    assert_stdout(p, &["16"]);
+}
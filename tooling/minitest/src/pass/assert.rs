@@ -0,0 +1,50 @@
+use crate::*;
+
+/// Function 1 asserts `cond` with the message stored in global 0,
+/// `main` catches the panic and prints whether one happened.
+fn assert_program(cond: bool, msg: &str) -> Program {
+    let b0 = block!( assert(const_bool(cond), 0, 1) );
+    let b1 = block!( return_() );
+    let f = function(Ret::Yes, 0, &[<()>::get_ptype()], &[b0, b1]);
+
+    let locals = [<bool>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        catch_unwind(1, local(0), 1),
+    );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    program_with_globals(&[main, f], &[global_str(msg)])
+}
+
+#[test]
+fn assert_true_continues() {
+    let p = assert_program(true, "unreachable");
+    assert_stdout(p, &["false"]);
+    assert_stderr(p, &[]);
+}
+
+#[test]
+fn assert_false_panics_with_message() {
+    let p = assert_program(false, "x == y");
+    assert_stdout(p, &["true"]);
+    assert_stderr(p, &["assertion failed: x == y"]);
+}
+
+#[test]
+fn assert_false_in_main_aborts() {
+    let b0 = block!( assert(const_bool(false), 0, 1) );
+    let b1 = block!( exit() );
+    let main = function(Ret::No, 0, &[], &[b0, b1]);
+
+    assert_abort(program_with_globals(&[main], &[global_str("oops")]));
+}
+
+#[test]
+fn assert_formats_message_global() {
+    let p = assert_program(true, "x == y");
+    let dump = fmt_program(p);
+    assert!(dump.contains("assert<global(0)>(true)"), "{dump}");
+}
@@ -0,0 +1,78 @@
+use crate::*;
+
+/// Function 1 tail-calls function 2 with its argument plus one; function 2 doubles its argument.
+fn inc_then_double() -> [Function; 2] {
+    let locals = locals!(ret: u32, x: u32);
+    let b0 = block!(
+        become_call(fn_ptr(2), &[add::<u32>(load(local(1)), const_int::<u32>(1))]),
+    );
+    let inc = function(Ret::Yes, 1, &locals, &[b0]);
+
+    let b0 = block!(
+        assign(local(0), mul::<u32>(load(local(1)), const_int::<u32>(2))),
+        return_(),
+    );
+    let double = function(Ret::Yes, 1, &locals, &[b0]);
+
+    [inc, double]
+}
+
+#[test]
+fn become_returns_to_caller() {
+    let locals = locals!(r: u32);
+    let b0 = block!( storage_live(0), call(1, &[const_int::<u32>(5)], Some(local(0)), Some(1)) );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let [inc, double] = inc_then_double();
+    let p = program(&[main, inc, double]);
+    assert!(fmt_program(p).contains("become f2(load(_1) +<u32> 1_u32);"));
+    assert_stdout(p, &["12"]);
+}
+
+/// Counts down from 100 by tail calls. This needs only one stack frame for the countdown,
+/// so it works with a stack depth limit of 2.
+#[test]
+fn become_does_not_grow_stack() {
+    let locals = locals!(ret: u32);
+    let b0 = block!( storage_live(0), call(1, &[const_int::<u32>(100)], Some(local(0)), Some(1)) );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let locals = locals!(ret: u32, n: u32);
+    let b0 = block!( if_(eq(load(local(1)), const_int::<u32>(0)), 1, 2) );
+    let b1 = block!( assign(local(0), const_int::<u32>(42)), return_() );
+    let b2 = block!( become_call(fn_ptr(1), &[sub::<u32>(load(local(1)), const_int::<u32>(1))]) );
+    let countdown = function(Ret::Yes, 1, &locals, &[b0, b1, b2]);
+
+    let p = program(&[main, countdown]);
+    assert_eq!(run_program_with_max_stack_depth(p, 2), TerminationInfo::MachineStop { code: Int::ZERO });
+    assert_stdout(p, &["42"]);
+}
+
+/// The entry function of a thread tail-calls another function; `Join` returns the callee's value.
+#[test]
+fn become_in_thread_entry() {
+    let locals = locals!(ret: u32);
+    let b0 = block!( become_call(fn_ptr(2), &[]) );
+    let entry = function(Ret::Yes, 0, &locals, &[b0]);
+
+    let b0 = block!( assign(local(0), const_int::<u32>(7)), return_() );
+    let seven = function(Ret::Yes, 0, &locals, &[b0]);
+
+    let locals = locals!(thread: u32, result: u32);
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        spawn(fn_ptr(1), Some(local(0)), 1),
+    );
+    let b1 = block!( join_with_ret(load(local(0)), local(1), 2) );
+    let b2 = block!( print(load(local(1)), 3) );
+    let b3 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[main, entry, seven]);
+    assert_stdout(p, &["7"]);
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+#[test]
+fn black_box_returns_input() {
+    let locals = locals!(x: (u8, u32), y: (u8, u32));
+    let pair = <(u8, u32)>::get_type();
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_tuple(&[const_int::<u8>(3), const_int::<u32>(40)], pair)),
+        black_box(load(local(0)), local(1), 1)
+    );
+    let b1 = block!( print(load(field(local(1), 0)), 2) );
+    let b2 = block!( print(load(field(local(1), 1)), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = program(&[f]);
+    assert!(fmt_program(p).contains("_1 = black_box(load(_0)) -> bb1;"));
+    assert_stdout(p, &["3", "40"]);
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Branches on `hint(cond)` and prints which branch was taken.
+fn branch_on_hint(hint: fn(ValueExpr, PlaceExpr, u32) -> Terminator, cond: bool) -> Program {
+    let locals = locals!(c: bool);
+
+    let b0 = block!(
+        storage_live(0),
+        hint(const_bool(cond), local(0), 1)
+    );
+    let b1 = block!( if_(load(local(0)), 2, 3) );
+    let b2 = block!( print(const_int::<u32>(1), 4) );
+    let b3 = block!( print(const_int::<u32>(0), 4) );
+    let b4 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    program(&[f])
+}
+
+#[test]
+fn branch_hints_are_identities() {
+    for hint in [likely, unlikely] {
+        assert_stdout(branch_on_hint(hint, true), &["1"]);
+        assert_stdout(branch_on_hint(hint, false), &["0"]);
+    }
+}
+
+#[test]
+fn fmt_branch_hints() {
+    let out = fmt_program(branch_on_hint(likely, true));
+    assert!(out.contains("_0 = likely(true) -> bb1;"), "{out}");
+    let out = fmt_program(branch_on_hint(unlikely, false));
+    assert!(out.contains("_0 = unlikely(false) -> bb1;"), "{out}");
+}
@@ -0,0 +1,81 @@
+use crate::*;
+
+type CasBuilder = fn(PlaceExpr, ValueExpr, ValueExpr, ValueExpr, u32) -> Terminator;
+
+/// Two threads increment a shared counter with a loop around `cas`, then the counter is printed.
+fn cas_loop_counter_program(cas: CasBuilder) -> Program {
+    let increment = {
+        // local(0): the iteration count, local(1): the value read, local(2): the CAS result.
+        let locals = [<u32>::get_ptype(), <u32>::get_ptype(), <(u32, bool)>::get_ptype()];
+        let counter = addr_of(global::<u32>(0), <*const u32>::get_type());
+
+        let b0 = block!(
+            storage_live(0),
+            storage_live(1),
+            storage_live(2),
+            assign(local(0), const_int::<u32>(0)),
+            goto(1)
+        );
+        let b1 = block!( if_(lt(load(local(0)), const_int::<u32>(10)), 2, 6) );
+        let b2 = block!( atomic_read(local(1), counter, 3) );
+        let b3 = block!(
+            cas(
+                local(2),
+                counter,
+                load(local(1)),
+                add::<u32>(load(local(1)), const_int::<u32>(1)),
+                4
+            )
+        );
+        // Retry if another thread modified the counter in the meantime.
+        let b4 = block!( if_(load(field(local(2), 1)), 5, 2) );
+        let b5 = block!(
+            assign(local(0), add::<u32>(load(local(0)), const_int::<u32>(1))),
+            goto(1)
+        );
+        let b6 = block!( return_() );
+
+        function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6])
+    };
+
+    // The locals store the thread ids.
+    let locals = [<u32>::get_ptype(); 2];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(global::<u32>(0), const_int::<u32>(0)),
+        spawn(fn_ptr(1), Some(local(0)), 1)
+    );
+    let b1 = block!( spawn(fn_ptr(1), Some(local(1)), 2) );
+    let b2 = block!( join(load(local(0)), 3) );
+    let b3 = block!( join(load(local(1)), 4) );
+    let b4 = block!( print(load(global::<u32>(0)), 5) );
+    let b5 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    // global(0) is the counter.
+    let globals = [global_int::<u32>()];
+    program_with_globals(&[f, increment], &globals)
+}
+
+/// No increment may get lost, regardless of the interleaving.
+#[test]
+fn cas_loop_counter() {
+    let p = cas_loop_counter_program(compare_exchange);
+    for _ in 0..10 {
+        let out = get_stdout(p).unwrap();
+        assert_eq!(out[0], "20");
+    }
+}
+
+/// Spurious failures of a weak compare-exchange only cause extra iterations of the loop.
+#[test]
+fn weak_cas_loop_counter() {
+    let p = cas_loop_counter_program(compare_exchange_weak);
+    for _ in 0..10 {
+        let out = get_stdout(p).unwrap();
+        assert_eq!(out[0], "20");
+    }
+}
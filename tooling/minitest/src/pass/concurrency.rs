@@ -71,10 +71,7 @@ fn arbitrary_order() {
     let mut write_2 = false;
 
     for _ in 0..20 {
-        let out = match get_stdout(p) {
-            Ok(out) => out,
-            Err(err) => panic!("{:?}", err),
-        };
+        let out = run_expect_ok(p);
 
         if out[0] == "1" { write_1 = true; }
         if out[0] == "2" { write_2 = true; }
@@ -0,0 +1,61 @@
+use crate::*;
+
+/// A consumer waits on a condvar until a producer has filled the buffer.
+/// Whichever thread gets the lock first, the consumer always sees the produced value.
+#[test]
+fn producer_consumer() {
+    // The producer puts 1 into the buffer at global(2) and notifies the consumer.
+    fn producer() -> Function {
+        let b0 = block!(
+            acquire(load(global::<u32>(0)), 1)
+        );
+        let b1 = block!(
+            assign(global::<u32>(2), const_int::<u32>(1)),
+            notify_one(load(global::<u32>(1)), 2)
+        );
+        let b2 = block!(
+            release(load(global::<u32>(0)), 3)
+        );
+        let b3 = block!(return_());
+
+        function(Ret::No, 0, &[], &[b0, b1, b2, b3])
+    }
+
+    // The main function is the consumer.
+    // The local is used to store the thread id of the producer.
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!( create_lock(global::<u32>(0), 1) );
+    let b1 = block!( create_condvar(global::<u32>(1), 2) );
+    let b2 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 3)
+    );
+    let b3 = block!( acquire(load(global::<u32>(0)), 4) );
+
+    // Wait as long as the buffer is empty.
+    let b4 = block!(
+        if_(eq(load(global::<u32>(2)), const_int::<u32>(0)), 5, 6)
+    );
+    let b5 = block!(
+        condvar_wait(load(global::<u32>(1)), load(global::<u32>(0)), 4)
+    );
+
+    let b6 = block!( print(load(global::<u32>(2)), 7) );
+    let b7 = block!( release(load(global::<u32>(0)), 8) );
+    let b8 = block!( join(load(local(0)), 9) );
+    let b9 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6, b7, b8, b9]);
+
+    // global(0) stores the lock id, global(1) the condvar id.
+    // global(2) is the buffer.
+    let globals = [global_int::<u32>(), global_int::<u32>(), global_int::<u32>()];
+
+    let p = program_with_globals(&[main, producer()], &globals);
+
+    for _ in 0..20 {
+        let out = run_expect_ok(p);
+        assert_eq!(out[0], "1");
+    }
+}
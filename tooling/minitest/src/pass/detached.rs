@@ -0,0 +1,73 @@
+use crate::*;
+
+/// A thread that never terminates.
+fn spin_forever() -> Function {
+    let b0 = block!( goto(0) );
+    function(Ret::No, 0, &[], &[b0])
+}
+
+/// `exit` stops the machine even though the spawned thread is still running.
+#[test]
+fn main_exits_while_thread_runs() {
+    let locals = locals!(thread: u32);
+    let b0 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 1),
+    );
+    let b1 = block!( print(const_int::<u32>(1), 2) );
+    let b2 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = program(&[main, spin_forever()]);
+    assert_stdout(p, &["1"]);
+}
+
+/// A spawned thread can stop the machine while main is blocked on joining it.
+#[test]
+fn thread_exits_while_main_joins() {
+    let b0 = block!( exit() );
+    let exiting = function(Ret::No, 0, &[], &[b0]);
+
+    let locals = locals!(thread: u32);
+    let b0 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 1),
+    );
+    let b1 = block!( join(load(local(0)), 2) );
+    let b2 = block!( print(const_int::<u32>(1), 3) );
+    let b3 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = program(&[main, exiting]);
+    assert_stdout(p, &[]);
+}
+
+/// Main joins a thread that returns 7. In some schedules the thread has already terminated
+/// when main joins it, then the join returns right away. Checking every schedule covers both cases:
+/// if the joined value was not 7, main would reach `unreachable`.
+#[test]
+fn join_finished_thread() {
+    let locals = locals!(ret: u32);
+    let b0 = block!(
+        assign(local(0), const_int::<u32>(7)),
+        return_(),
+    );
+    let seven = function(Ret::Yes, 0, &locals, &[b0]);
+
+    let locals = locals!(thread: u32, result: u32);
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        spawn(fn_ptr(1), Some(local(0)), 1),
+    );
+    let b1 = block!( join_with_ret(load(local(0)), local(1), 2) );
+    let b2 = block!( if_(eq(load(local(1)), const_int::<u32>(7)), 3, 4) );
+    let b3 = block!( print(load(local(1)), 5) );
+    let b4 = block!( unreachable() );
+    let b5 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+    let p = program(&[main, seven]);
+    assert!(explore_all(p, 100).is_ok());
+    assert_stdout(p, &["7"]);
+}
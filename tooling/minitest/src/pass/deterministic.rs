@@ -0,0 +1,99 @@
+use crate::*;
+
+/// Spawns two threads running `fn_ptr(1)` and joins them, then prints global(0).
+fn two_threads(worker: Function) -> Program {
+    // The locals store the thread ids.
+    let locals = [<u32>::get_ptype(); 2];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(global::<u32>(0), const_int::<u32>(0)),
+        spawn(fn_ptr(1), Some(local(0)), 1)
+    );
+    let b1 = block!( spawn(fn_ptr(1), Some(local(1)), 2) );
+    let b2 = block!( join(load(local(0)), 3) );
+    let b3 = block!( join(load(local(1)), 4) );
+    let b4 = block!( print(load(global::<u32>(0)), 5) );
+    let b5 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    let globals = [global_int::<u32>()];
+    program_with_globals(&[main, worker], &globals)
+}
+
+/// Both threads atomically add 1 to the counter, so the result is always the same.
+#[test]
+fn atomic_increments_deterministic() {
+    let locals = [<(u32, bool)>::get_ptype()];
+    let counter = addr_of(global::<u32>(0), <*const u32>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        compare_exchange(local(0), counter, const_int::<u32>(0), const_int::<u32>(1), 1)
+    );
+    // If the other thread came first, the counter is 1 and we bump it to 2.
+    let b1 = block!( if_(load(field(local(0), 1)), 3, 2) );
+    let b2 = block!( compare_exchange(local(0), counter, const_int::<u32>(1), const_int::<u32>(2), 3) );
+    let b3 = block!( return_() );
+
+    let worker = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = two_threads(worker);
+
+    assert!(check_deterministic(p, 20).is_ok());
+}
+
+/// Each thread prints its own id, so the output depends on which thread runs first.
+#[test]
+fn print_order_diverges() {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        thread_id(local(0), 1)
+    );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!( return_() );
+
+    let worker = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = two_threads(worker);
+
+    // With 100 executions, both orders show up with overwhelming probability.
+    let Err(div) = check_deterministic(p, 100) else {
+        panic!("expected the print order to differ between executions");
+    };
+    assert_eq!(div.first_run, 0);
+    assert_ne!(div.first.stdout, div.second.stdout);
+    assert_eq!(div.first.termination, div.second.termination);
+}
+
+/// Each thread prints `10 * id + 1` and then `10 * id + 2`. Whatever the schedule,
+/// the output is an interleaving that keeps the lines of each thread in order.
+#[test]
+fn print_interleaving_keeps_thread_order() {
+    let locals = [<u32>::get_ptype()];
+    let line = |n| add::<u32>(mul::<u32>(load(local(0)), const_int::<u32>(10)), const_int::<u32>(n));
+
+    let b0 = block!(
+        storage_live(0),
+        thread_id(local(0), 1)
+    );
+    let b1 = block!( print(line(1), 2) );
+    let b2 = block!( print(line(2), 3) );
+    let b3 = block!( return_() );
+
+    let worker = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = two_threads(worker);
+
+    for _ in 0..20 {
+        let out = run_expect_ok(p);
+        assert_eq!(out.len(), 5);
+        assert_eq!(out[4], "0");
+        for id in [1, 2] {
+            let first = out.iter().position(|l| *l == format!("{id}1")).unwrap();
+            let second = out.iter().position(|l| *l == format!("{id}2")).unwrap();
+            assert!(first < second, "lines of thread {id} are out of order: {out:?}");
+        }
+    }
+}
@@ -0,0 +1,13 @@
+use crate::*;
+
+/// Signed division and remainder round towards zero, and `MIN / 1` is fine.
+#[test]
+fn div_rem_signed() {
+    let b0 = block!( print(div::<i32>(const_int::<i32>(-7), const_int::<i32>(2)), 1) );
+    let b1 = block!( print(rem::<i32>(const_int::<i32>(-7), const_int::<i32>(2)), 2) );
+    let b2 = block!( print(div::<i32>(const_int::<i32>(i32::MIN), const_int::<i32>(1)), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2, b3]);
+    assert_stdout(program(&[f]), &["-3", "-1", "-2147483648"]);
+}
@@ -0,0 +1,35 @@
+use crate::*;
+
+/// Function 1 prints `1` and exits, so `main` calls it without a next block.
+#[test]
+fn diverging_call_into_exit() {
+    let b0 = block!( call_diverging(1, &[]) );
+    let main = function(Ret::No, 0, &[], &[b0]);
+
+    let b0 = block!( print(const_int::<u32>(1), 1) );
+    let b1 = block!( exit() );
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+
+    assert_stdout(program(&[main, f]), &["1"]);
+}
+
+/// Function 1 returns `2`, so `main` continues in its next block.
+#[test]
+fn returning_call() {
+    let locals = [<u32>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        call(1, &[], Some(local(0)), Some(1)),
+    );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let b0 = block!(
+        assign(local(0), const_int::<u32>(2)),
+        return_(),
+    );
+    let f = function(Ret::Yes, 0, &locals, &[b0]);
+
+    assert_stdout(program(&[main, f]), &["2"]);
+}
@@ -21,3 +21,25 @@ fn dynamic_memory() {
     let p = program(&[f]);
     assert_stop(p);
 }
+
+#[test]
+fn deref_roundtrip() {
+    let locals = [<*mut u64>::get_ptype()];
+    let n = const_int::<usize>(8);
+    let place = || deref(load(local(0)), <u64>::get_ptype());
+    let b0 = block!(storage_live(0), allocate(n, n, local(0), 1));
+    let b1 = block!(
+        assign(place(), const_int::<u64>(7)),
+        print(load(place()), 2),
+    );
+    let b2 = block!(deallocate(load(local(0)), n, n, 3));
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    assert_stdout(program(&[f]), &["7"]);
+}
+
+#[test]
+#[should_panic(expected = "deref requires a data pointer!")]
+fn deref_non_pointer() {
+    deref(const_int::<usize>(0), <u64>::get_ptype());
+}
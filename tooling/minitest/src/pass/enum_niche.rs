@@ -0,0 +1,80 @@
+use crate::*;
+
+/// An `Option<&i32>`-like enum: `None` is the null pointer, `Some` is just the reference.
+/// It has the size of a pointer, there is no separate tag.
+fn option_ref_ty() -> Type {
+    let none_ty = <()>::get_type();
+    let some_ty = tuple_ty(&[(size(0), <&i32>::get_type())], size(8));
+    niche_enum_ty::<usize>(&[none_ty, some_ty], 1, size(0), &[(0, 0)], size(8))
+}
+
+/// Stores `val` (which may refer to local(1)) to local(0), then prints
+/// - the discriminant,
+/// - the raw bits of the enum, read as a `usize`.
+fn print_option_ref(val: ValueExpr) -> Program {
+    let locals = [ptype(option_ref_ty(), align(8)), <i32>::get_ptype()];
+
+    let bits_ptr = addr_of(local(0), <*const usize>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), const_int::<i32>(42)),
+        assign(local(0), val),
+        print(discriminant::<u32>(local(0)), 1)
+    );
+    let b1 = block!( print(load(deref(bits_ptr, <usize>::get_ptype())), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f])
+}
+
+#[test]
+fn niche_none_is_null() {
+    let p = print_option_ref(variant(0, const_unit(), option_ref_ty()));
+    dump_program(p);
+    assert_stdout(p, &["0", "0"]);
+}
+
+#[test]
+fn niche_some_is_pointer() {
+    let ty = option_ref_ty();
+    let Type::Enum { variants, .. } = ty else { unreachable!() };
+    let some_ty = variants.get(Int::ONE).unwrap();
+    let payload = const_tuple(&[addr_of(local(1), <&i32>::get_type())], some_ty);
+
+    let p = print_option_ref(variant(1, payload, ty));
+    let out = get_stdout(p).unwrap();
+    assert_eq!(out[0], "1");
+    // The bits are the address of local(1), which is never null.
+    assert_ne!(out[1], "0");
+}
+
+/// Reads `Some(&x)` back through a pointer to the reference and dereferences it.
+#[test]
+fn niche_some_roundtrip() {
+    let ty = option_ref_ty();
+    let Type::Enum { variants, .. } = ty else { unreachable!() };
+    let some_ty = variants.get(Int::ONE).unwrap();
+    let payload = const_tuple(&[addr_of(local(1), <&i32>::get_type())], some_ty);
+
+    let locals = [ptype(ty, align(8)), <i32>::get_ptype(), <&i32>::get_ptype()];
+    let ref_ptr = addr_of(local(0), <*const &i32>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(1), const_int::<i32>(42)),
+        assign(local(0), variant(1, payload, ty)),
+        assign(local(2), load(deref(ref_ptr, <&i32>::get_ptype()))),
+        print(load(deref(load(local(2)), <i32>::get_ptype())), 1)
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    let out = get_stdout(p).unwrap();
+    assert_eq!(out[0], "42");
+}
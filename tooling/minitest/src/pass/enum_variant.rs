@@ -0,0 +1,98 @@
+use crate::*;
+
+/// An `Option<i32>`-like enum: the tag is a `u32` at offset 0, the payload of `Some` is at offset 4.
+fn option_i32_ty() -> Type {
+    let none_ty = <()>::get_type();
+    let some_ty = tuple_ty(&[(size(4), <i32>::get_type())], size(8));
+    enum_ty::<u32>(&[none_ty, some_ty], size(0), size(8))
+}
+
+/// Stores `val` to a local, reads the tag through a raw pointer and branches on it.
+/// Prints the payload for `Some`, and -1 for `None`.
+fn print_option(val: ValueExpr) -> Program {
+    let opt_ty = option_i32_ty();
+    let locals = [ptype(opt_ty, align(4)), <u32>::get_ptype()];
+
+    let tag_ptr = addr_of(local(0), <*const u32>::get_type());
+    let payload_ptr = offset_inbounds(addr_of(local(0), <*const i32>::get_type()), const_int::<usize>(4));
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), val),
+        assign(local(1), load(deref(tag_ptr, <u32>::get_ptype()))),
+        if_(eq(load(local(1)), const_int::<u32>(1)), 1, 2)
+    );
+    let b1 = block!( print(load(deref(payload_ptr, <i32>::get_ptype())), 3) );
+    let b2 = block!( print(const_int::<i32>(-1), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn some_variant() {
+    let opt_ty = option_i32_ty();
+    let Type::Enum { variants, .. } = opt_ty else { unreachable!() };
+    let payload = const_tuple(&[const_int::<i32>(7)], variants.get(Int::ONE).unwrap());
+
+    let p = print_option(variant(1, payload, opt_ty));
+    dump_program(p);
+    assert_stdout(p, &["7"]);
+}
+
+#[test]
+fn none_variant() {
+    let p = print_option(variant(0, const_unit(), option_i32_ty()));
+    assert_stdout(p, &["-1"]);
+}
+
+/// An enum with a fieldless variant and two variants with payloads of different sizes.
+/// The tag is a `u8` at offset 0.
+fn three_variants_ty() -> Type {
+    let a_ty = <()>::get_type();
+    let b_ty = tuple_ty(&[(size(1), <u8>::get_type())], size(2));
+    let c_ty = tuple_ty(&[(size(2), <u16>::get_type())], size(4));
+    enum_ty::<u8>(&[a_ty, b_ty, c_ty], size(0), size(4))
+}
+
+/// Stores `val`, reads its discriminant and branches on it.
+/// Prints the discriminant, then `true` if the branch for `expected` was taken.
+fn branch_on_discriminant(val: ValueExpr, expected: u32) -> Program {
+    let locals = [ptype(three_variants_ty(), align(2)), <u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), val),
+        assign(local(1), discriminant::<u32>(local(0))),
+        print(load(local(1)), 1)
+    );
+    let b1 = block!( if_(eq(load(local(1)), const_int::<u32>(expected)), 2, 3) );
+    let b2 = block!( print(const_bool(true), 4) );
+    let b3 = block!( print(const_bool(false), 4) );
+    let b4 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    program(&[f])
+}
+
+#[test]
+fn discriminant_branches() {
+    let ty = three_variants_ty();
+    let Type::Enum { variants, .. } = ty else { unreachable!() };
+    let b_ty = variants.get(Int::from(1)).unwrap();
+    let c_ty = variants.get(Int::from(2)).unwrap();
+
+    let vals = [
+        variant(0, const_unit(), ty),
+        variant(1, const_tuple(&[const_int::<u8>(42)], b_ty), ty),
+        variant(2, const_tuple(&[const_int::<u16>(300)], c_ty), ty),
+    ];
+    for (expected, val) in vals.into_iter().enumerate() {
+        let p = branch_on_discriminant(val, expected as u32);
+        let out = get_stdout(p).unwrap();
+        assert_eq!(out[..2], [expected.to_string(), String::from("true")]);
+    }
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+#[test]
+fn exit_code_42() {
+    let b0 = block!(exit_code(const_int::<i32>(42)));
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program(&[f]);
+    assert_eq!(get_exit_code(p), Ok(Int::from(42)));
+}
+
+#[test]
+fn exit_code_default() {
+    let b0 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program(&[f]);
+    assert_eq!(get_exit_code(p), Ok(Int::ZERO));
+}
+
+#[test]
+fn assert_exit_code_7() {
+    let b0 = block!(exit_code(const_int::<i32>(7)));
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program(&[f]);
+    assert_exit_code(p, 7);
+}
+
+#[test]
+#[should_panic(expected = "program exited with code 7, expected 8")]
+fn assert_exit_code_mismatch() {
+    let b0 = block!(exit_code(const_int::<i32>(7)));
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program(&[f]);
+    assert_exit_code(p, 8);
+}
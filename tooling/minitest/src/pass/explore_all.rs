@@ -0,0 +1,64 @@
+use crate::*;
+
+/// The main thread and a spawned thread both write to global(1),
+/// protected by the lock whose id is stored at global(0).
+fn two_locked_writers() -> Program {
+    let enter = |next| acquire(load(global::<u32>(0)), next);
+    let leave = |next| release(load(global::<u32>(0)), next);
+
+    let b0 = block!( enter(1) );
+    let b1 = block!(
+        assign(global::<u32>(1), const_int::<u32>(1)),
+        leave(2)
+    );
+    let b2 = block!( return_() );
+    let worker = function(Ret::No, 0, &[], &[b0, b1, b2]);
+
+    let locals = locals!(thread: u32);
+    let b0 = block!( create_lock(global::<u32>(0), 1) );
+    let b1 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 2)
+    );
+    let b2 = block!( enter(3) );
+    let b3 = block!(
+        assign(global::<u32>(1), const_int::<u32>(2)),
+        leave(4)
+    );
+    let b4 = block!( join(load(local(0)), 5) );
+    let b5 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    let globals = [global_int::<u32>(); 2];
+    program_with_globals(&[main, worker], &globals)
+}
+
+#[test]
+fn explore_all_clears_locked() {
+    assert!(explore_all(two_locked_writers(), 100).is_ok());
+}
+
+/// Whether a thread is spawned depends on the (random) address of local 0,
+/// so replaying a schedule can ask for a thread that does not exist in this execution.
+/// Such schedules are skipped.
+#[test]
+fn explore_all_address_dependent_spawn() {
+    let worker = function(Ret::No, 0, &[], &[block!( return_() )]);
+
+    let locals = locals!(x: u32, thread: u32);
+    let addr = ptr_to_int(addr_of(local(0), <*const u32>::get_type()));
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        if_(eq(rem::<usize>(addr, const_int::<usize>(8)), const_int::<usize>(0)), 1, 3)
+    );
+    let b1 = block!( spawn(fn_ptr(1), Some(local(1)), 2) );
+    let b2 = block!( join(load(local(1)), 3) );
+    let b3 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[main, worker]);
+    for _ in 0..10 {
+        assert!(explore_all(p, 100).is_ok());
+    }
+}
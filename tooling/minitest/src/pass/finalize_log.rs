@@ -0,0 +1,95 @@
+use crate::*;
+
+/// Stores a reference to local(0) in local(1), then finalizes local(1).
+#[test]
+fn finalize_is_logged() {
+    let locals = [<u32>::get_ptype(), <&u32>::get_ptype()];
+
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<u32>(42)),
+        assign(local(1), addr_of(local(0), <&u32>::get_type())),
+        finalize(local(1), true),
+    ];
+    let p = small_program(&locals, &stmts);
+
+    let (info, log) = run_program_with_finalize_log(p, RunConfig::default());
+    assert_eq!(info, TerminationInfo::MachineStop { code: Int::ZERO });
+    assert_eq!(log.len(), 1);
+
+    let event = &log[0];
+    assert_eq!(event.stmt, Int::from(4));
+    assert_eq!(event.ty, Some(<&u32>::get_type()));
+    assert!(event.fn_entry);
+    // The reference keeps its provenance.
+    assert_eq!(event.bytes.len(), Int::from(8));
+    assert!(event.bytes.iter().all(|b| matches!(b, AbstractByte::Init(_, Some(_)))));
+}
+
+/// Programs without `Finalize` produce an empty log.
+#[test]
+fn no_finalize_no_log() {
+    let locals = [<u32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), const_int::<u32>(42)),
+    ];
+    let p = small_program(&locals, &stmts);
+
+    let (info, log) = run_program_with_finalize_log(p, RunConfig::default());
+    assert_eq!(info, TerminationInfo::MachineStop { code: Int::ZERO });
+    assert!(log.is_empty());
+}
+
+/// Finalizing a zero-sized place at a dangling address logs no bytes.
+#[test]
+fn finalize_zero_sized_is_logged() {
+    let place = deref(int_to_ptr_invalid(const_int::<usize>(8), <*const ()>::get_type()), <()>::get_ptype());
+    let stmts = [
+        finalize(place, false),
+    ];
+    let p = small_program(&[], &stmts);
+
+    let (info, log) = run_program_with_finalize_log(p, RunConfig::default());
+    assert_eq!(info, TerminationInfo::MachineStop { code: Int::ZERO });
+    assert_eq!(log.len(), 1);
+
+    let event = &log[0];
+    assert_eq!(event.ty, Some(<()>::get_type()));
+    assert_eq!(event.addr, Int::from(8));
+    assert!(event.bytes.is_empty());
+}
+
+/// The tag of the pointer stored in `bytes`.
+fn tag(bytes: List<AbstractByte<BasicProvenance>>) -> Tag {
+    let Some(AbstractByte::Init(_, Some(provenance))) = bytes.first() else {
+        panic!("no pointer in the finalized bytes");
+    };
+    provenance.tag
+}
+
+/// Finalizes a mutable reference twice. With aliasing checks, every retag gives it a new tag.
+#[test]
+fn finalize_mut_ref_retags() {
+    let locals = [<u32>::get_ptype(), <&mut u32>::get_ptype()];
+
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<u32>(42)),
+        assign(local(1), addr_of(local(0), <&mut u32>::get_type())),
+        finalize(local(1), false),
+        finalize(local(1), false),
+    ];
+    let p = small_program(&locals, &stmts);
+
+    let (info, log) = run_program_with_finalize_log(p, RunConfig::default());
+    assert_eq!(info, TerminationInfo::MachineStop { code: Int::ZERO });
+    assert_eq!(tag(log[0].bytes), tag(log[1].bytes));
+
+    let (info, log) = run_program_with_finalize_log(p, RunConfig { check_aliasing: true, ..RunConfig::default() });
+    assert_eq!(info, TerminationInfo::MachineStop { code: Int::ZERO });
+    assert_eq!(log.len(), 2);
+    assert_ne!(tag(log[0].bytes), tag(log[1].bytes));
+}
@@ -0,0 +1,59 @@
+use crate::*;
+
+/// Nested composite types are printed once each, before the functions, and referred to by name.
+#[test]
+fn nested_comptypes_table() {
+    let inner = <(u8, u16)>::get_type();
+    let outer = tuple_ty(&[(size(0), inner), (size(4), inner)], size(8));
+    let locals = [ptype(outer, align(2)), ptype(inner, align(2))];
+    let stmts = [storage_live(0), storage_live(1)];
+    let p = small_program(&locals, &stmts);
+
+    let expected = "\
+tuple T0 (4 bytes) {
+  at byte 0: u8,
+  at byte 2: u16,
+}
+
+tuple T1 (8 bytes) {
+  at byte 0: T0,
+  at byte 4: T0,
+}
+
+start fn f0() -/> {
+  let _0: T1@align(2);
+  let _1: T0@align(2);
+  start bb0:
+    storage_live(_0);
+    storage_live(_1);
+    _ = exit();
+}
+
+";
+    assert_eq!(fmt_program(p), expected);
+}
+
+/// A function with a single local of type `ty`.
+fn uses_type(ty: Type) -> Function {
+    let locals = [ptype(ty, align(4))];
+    let b0 = block!(storage_live(0), return_());
+    function(Ret::No, 0, &locals, &[b0])
+}
+
+/// The type table only depends on which composite types are used, not on where they are used first.
+#[test]
+fn comptype_names_are_canonical() {
+    let a = <(u8, u32)>::get_type();
+    let b = <(u16, u32)>::get_type();
+    let main = function(Ret::No, 0, &[], &[block!(exit())]);
+
+    let p1 = program(&[main, uses_type(a), uses_type(b)]);
+    let p2 = program(&[main, uses_type(b), uses_type(a)]);
+
+    let types_of = |p: Program| {
+        let out = fmt_program(p);
+        let end = out.find("start fn").unwrap();
+        out[..end].to_string()
+    };
+    assert_eq!(types_of(p1), types_of(p2));
+}
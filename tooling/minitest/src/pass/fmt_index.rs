@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Stores `[[1, 2], [3, 4]]` in local 0 and a pointer to it in local 1,
+/// then loads `a[1][0]` once directly and once through the pointer.
+fn nested_index_program() -> Program {
+    let row = |a, b| array(&[const_int::<i32>(a), const_int::<i32>(b)], <i32>::get_type());
+    let value = array(&[row(1, 2), row(3, 4)], <[i32; 2]>::get_type());
+    let elem = |root| index(index(root, const_int::<usize>(1)), const_int::<usize>(0));
+
+    let locals = [<[[i32; 2]; 2]>::get_ptype(), <*const [[i32; 2]; 2]>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), value),
+        assign(local(1), addr_of(local(0), <*const [[i32; 2]; 2]>::get_type())),
+        print(load(elem(local(0))), 1),
+    );
+    let b1 = block!(
+        print(load(elem(deref(load(local(1)), <[[i32; 2]; 2]>::get_ptype()))), 2),
+    );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f])
+}
+
+#[test]
+fn nested_index_is_minimal() {
+    let p = nested_index_program();
+    let out = fmt_program(p);
+    assert!(out.contains("load(_0[1_u64][0_u64])"), "{out}");
+    assert!(out.contains("load(deref<[[i32; 2]; 2]@align(4)>(load(_1))[1_u64][0_u64])"), "{out}");
+    assert_stdout(p, &["3", "3"]);
+}
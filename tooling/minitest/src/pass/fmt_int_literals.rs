@@ -0,0 +1,35 @@
+use crate::*;
+
+/// Formats a program that prints each of `values`.
+fn fmt_prints(values: &[ValueExpr]) -> String {
+    let mut blocks: Vec<_> = values.iter().enumerate()
+        .map(|(i, v)| block!( print(*v, i as u32 + 1) ))
+        .collect();
+    blocks.push(block!( exit() ));
+    let f = function(Ret::No, 0, &[], &blocks);
+    fmt_program(program(&[f]))
+}
+
+#[test]
+fn int_literals_are_annotated() {
+    let out = fmt_prints(&[
+        const_int::<i32>(7),
+        const_int::<u8>(255),
+        const_int::<usize>(0),
+    ]);
+    assert!(out.contains("print(7_i32)"), "{out}");
+    assert!(out.contains("print(255_u8)"), "{out}");
+    assert!(out.contains("print(0_u64)"), "{out}");
+}
+
+#[test]
+fn int_literals_at_bounds() {
+    let out = fmt_prints(&[
+        const_int::<i8>(-128),
+        const_int::<i128>(i128::MIN),
+        const_int::<u128>(u128::MAX),
+    ]);
+    assert!(out.contains("print(-128_i8)"), "{out}");
+    assert!(out.contains("print(-170141183460469231731687303715884105728_i128)"), "{out}");
+    assert!(out.contains("print(340282366920938463463374607431768211455_u128)"), "{out}");
+}
@@ -0,0 +1,31 @@
+use crate::*;
+
+fn tuple_program() -> Program {
+    let locals = [<(u8, u16)>::get_ptype()];
+    let stmts = [storage_live(0)];
+    small_program(&locals, &stmts)
+}
+
+#[test]
+fn default_options() {
+    let p = tuple_program();
+    assert_eq!(fmt_program_with_options(p, &FmtOptions::default()), fmt_program(p));
+}
+
+#[test]
+fn custom_options() {
+    let p = tuple_program();
+    let options = FmtOptions { indent: 4, start_keyword: false, type_table: false };
+    let out = fmt_program_with_options(p, &options);
+
+    let expected = "\
+fn f0() -/> {
+    let _0: T0@align(2);
+    bb0:
+        storage_live(_0);
+        _ = exit();
+}
+
+";
+    assert_eq!(out, expected);
+}
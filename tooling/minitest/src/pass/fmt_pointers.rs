@@ -0,0 +1,40 @@
+use crate::*;
+
+/// A pointer constant to `offset` bytes into global(0).
+fn global_ptr(offset: u64) -> ValueExpr {
+    let relocation = Relocation {
+        name: GlobalName(Name::from_internal(0)),
+        offset: Size::from_bytes_const(offset),
+    };
+    ValueExpr::Constant(Constant::GlobalPointer(relocation), <*const u32>::get_type())
+}
+
+/// Loads a `u32` through `ptr` and prints it.
+fn load_through(ptr: ValueExpr) -> Program {
+    let b0 = block!( print(load(deref(ptr, <u32>::get_ptype())), 1) );
+    let b1 = block!( exit() );
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    program_with_globals(&[f], &[global_int::<[u32; 4]>()])
+}
+
+#[test]
+fn global_pointer_offsets_differ() {
+    let p0 = fmt_program(load_through(global_ptr(0)));
+    let p8 = fmt_program(load_through(global_ptr(8)));
+    assert_ne!(p0, p8);
+
+    assert!(p0.contains("deref<u32@align(4)>(global(0))"));
+    assert!(p8.contains("deref<u32@align(4)>(global(0) + 8)"));
+}
+
+/// Function pointers print as the name of the function.
+#[test]
+fn fn_pointer_name() {
+    let main = function(Ret::No, 0, &[], &[
+        block!( spawn(fn_ptr(1), None, 1) ),
+        block!( exit() ),
+    ]);
+    let other = function(Ret::No, 0, &[], &[block!( return_() )]);
+    let out = fmt_program(program(&[main, other]));
+    assert!(out.contains("spawn(f1)"));
+}
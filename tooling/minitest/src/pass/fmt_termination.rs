@@ -0,0 +1,17 @@
+use crate::*;
+
+#[test]
+fn fmt_termination_variants() {
+    let ub = TerminationInfo::Ub(minirust_rs::prelude::String::from_internal("division by zero".to_string()));
+    assert_eq!(fmt_termination_info(ub), "UB: division by zero");
+    assert_eq!(fmt_termination_info(TerminationInfo::MachineStop { code: Int::from(3) }), "program exited with code 3");
+    assert_eq!(fmt_termination_info(TerminationInfo::Deadlock), "ERR: deadlock, no thread can make progress");
+    assert_eq!(fmt_termination_info(TerminationInfo::Abort), "program aborted");
+    assert_eq!(fmt_termination_info(TerminationInfo::StackOverflow), "ERR: stack overflow");
+}
+
+#[test]
+fn fmt_termination_ill_formed() {
+    let info = run_program(program(&[]));
+    assert_eq!(fmt_termination_info(info), "ERR: program not well-formed: start function does not exist");
+}
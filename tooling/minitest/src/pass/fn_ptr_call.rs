@@ -0,0 +1,38 @@
+use crate::*;
+
+/// Function 1 adds one to its argument, function 2 doubles it.
+fn callees() -> [Function; 2] {
+    let locals = [<u32>::get_ptype(), <u32>::get_ptype()];
+    let b0 = block!(
+        assign(local(0), add::<u32>(load(local(1)), const_int::<u32>(1))),
+        return_(),
+    );
+    let inc = function(Ret::Yes, 1, &locals, &[b0]);
+
+    let b0 = block!(
+        assign(local(0), mul::<u32>(load(local(1)), const_int::<u32>(2))),
+        return_(),
+    );
+    let double = function(Ret::Yes, 1, &locals, &[b0]);
+
+    [inc, double]
+}
+
+#[test]
+fn call_through_local() {
+    let locals = [ptype(fn_ptr_ty(), <usize>::get_align()), <u32>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        if_(const_bool(false), 1, 2),
+    );
+    let b1 = block!( assign(local(0), fn_ptr(1)), goto(3) );
+    let b2 = block!( assign(local(0), fn_ptr(2)), goto(3) );
+    let b3 = block!( call_ptr(load(local(0)), &[const_int::<u32>(5)], Some(local(1)), Some(4)) );
+    let b4 = block!( print(load(local(1)), 5) );
+    let b5 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    let [inc, double] = callees();
+    assert_stdout(program(&[main, inc, double]), &["10"]);
+}
@@ -0,0 +1,37 @@
+use crate::*;
+
+/// Reads global(0) through a pointer to it, and prints the value.
+#[test]
+fn global_through_pointer() {
+    let locals = [<*const i32>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), addr_of(global::<i32>(0), <*const i32>::get_type())),
+        print(load(deref(load(local(0)), <i32>::get_ptype())), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program_with_globals(&[f], &[global_int_init::<i32>(-5)]);
+    assert_stdout(p, &["-5"]);
+}
+
+/// global(1) points to the second element of global(0), which is read through global(1).
+#[test]
+fn global_relocation() {
+    let arr = global_int_init::<u64>(7u64 << 32 | 3);
+    let ptr = global_pointer(0, size(4));
+
+    let b0 = block!( print(load(deref(load(global::<*const u32>(1)), <u32>::get_ptype())), 1) );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    let p = program_with_globals(&[f], &[arr, ptr]);
+    assert_stdout(p, &["7"]);
+}
+
+#[test]
+#[should_panic(expected = "value out of bounds")]
+fn global_int_init_out_of_bounds() {
+    global_int_init::<u8>(256);
+}
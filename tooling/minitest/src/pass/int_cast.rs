@@ -0,0 +1,28 @@
+use crate::*;
+
+/// Widening sign-extends signed operands and zero-extends unsigned ones,
+/// narrowing truncates.
+#[test]
+fn int_cast_extension() {
+    let b0 = block!( print(int_cast::<u32>(const_int::<i8>(-1)), 1) );
+    let b1 = block!( print(int_cast::<i32>(const_int::<u8>(255)), 2) );
+    let b2 = block!( print(int_cast::<i8>(const_int::<u32>(0x1ff)), 3) );
+    let b3 = block!( print(int_cast::<u8>(const_int::<i32>(-2)), 4) );
+    let b4 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2, b3, b4]);
+    assert_stdout(program(&[f]), &["4294967295", "255", "-1", "254"]);
+}
+
+/// `true` becomes 1 and `false` becomes 0.
+#[test]
+fn bool_to_int_cast() {
+    let b0 = block!( print(bool_to_int::<u8>(const_bool(true)), 1) );
+    let b1 = block!( print(bool_to_int::<i64>(const_bool(false)), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2]);
+    let p = program(&[f]);
+    assert!(fmt_program(p).contains("bool2int<u8>(true)"));
+    assert_stdout(p, &["1", "0"]);
+}
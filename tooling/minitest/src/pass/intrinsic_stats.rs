@@ -0,0 +1,64 @@
+use crate::*;
+
+/// Allocates and frees a buffer three times in a loop, and prints once.
+#[test]
+fn intrinsic_stats_alloc_churn() {
+    let locals = locals!(i: u32, buf: *mut u64);
+    let i = local(0);
+    let buf = local(1);
+    let n = const_int::<usize>(8);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(i, const_int::<u32>(0)),
+        goto(1),
+    );
+    // The loop occupies blocks 1 to 4.
+    let loop_blocks = while_loop(lt(load(i), const_int::<u32>(3)), |start, header| vec![
+        block!( allocate(n, n, buf, start + 1) ),
+        block!( deallocate(load(buf), n, n, start + 2) ),
+        block!(
+            assign(i, add::<u32>(load(i), const_int::<u32>(1))),
+            goto(header),
+        ),
+    ], 1, 5);
+    let b5 = block!( print(load(i), 6) );
+    let b6 = block!( exit() );
+
+    let mut blocks = vec![b0];
+    blocks.extend(loop_blocks);
+    blocks.extend([b5, b6]);
+
+    let f = function(Ret::No, 0, &locals, &blocks);
+    let p = program(&[f]);
+
+    let (info, stats) = run_program_with_stats(p);
+    assert_eq!(info, TerminationInfo::MachineStop { code: Int::ZERO });
+    assert_eq!(stats[&Intrinsic::Allocate], 3);
+    assert_eq!(stats[&Intrinsic::Deallocate], 3);
+    assert_eq!(stats[&Intrinsic::PrintStdout], 1);
+    assert_eq!(stats[&Intrinsic::Exit], 1);
+    assert_eq!(stats.len(), 4);
+}
+
+/// The call that raises UB is counted as well.
+#[test]
+fn intrinsic_stats_ub() {
+    let locals = locals!(p: *mut u64);
+    let n = const_int::<usize>(8);
+
+    let b0 = block!( storage_live(0), allocate(n, n, local(0), 1) );
+    let b1 = block!( deallocate(load(local(0)), n, n, 2) );
+    let b2 = block!( deallocate(load(local(0)), n, n, 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = program(&[f]);
+
+    let (info, stats) = run_program_with_stats(p);
+    assert!(matches!(info, TerminationInfo::Ub(_)));
+    assert_eq!(stats[&Intrinsic::Allocate], 1);
+    assert_eq!(stats[&Intrinsic::Deallocate], 2);
+    assert!(!stats.contains_key(&Intrinsic::Exit));
+}
@@ -0,0 +1,30 @@
+use crate::*;
+
+#[test]
+fn locals_macro_unnamed() {
+    let locals = locals!(u32, bool, [u8; 4], *const i16);
+    assert_eq!(locals, [
+        <u32>::get_ptype(),
+        <bool>::get_ptype(),
+        <[u8; 4]>::get_ptype(),
+        <*const i16>::get_ptype(),
+    ]);
+}
+
+#[test]
+fn locals_macro_named() {
+    let locals = locals!(counter: u32, done: bool);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<u32>(7)),
+        assign(local(1), eq(load(local(0)), const_int::<u32>(7))),
+        print(load(local(1)), 1)
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_stdout(p, &["true"]);
+}
@@ -0,0 +1,38 @@
+use crate::*;
+
+/// Prints 10, 20 or 0 depending on whether `value` is 1, 2 or something else.
+fn match_program(value: u32) -> Program {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_int::<u32>(value)),
+        goto(1),
+    );
+    // The chain occupies blocks 1 and 2.
+    let chain = match_int!(<u32> load(local(0)), 1; 1 => 3, 2 => 4, _ => 5);
+    let b3 = block!( print(const_int::<u32>(10), 6) );
+    let b4 = block!( print(const_int::<u32>(20), 6) );
+    let b5 = block!( print(const_int::<u32>(0), 6) );
+    let b6 = block!( exit() );
+
+    let mut blocks = vec![b0];
+    blocks.extend(chain);
+    blocks.extend([b3, b4, b5, b6]);
+
+    let f = function(Ret::No, 0, &locals, &blocks);
+    program(&[f])
+}
+
+#[test]
+fn match_int_arms() {
+    let p = match_program(2);
+    dump_program(p);
+    assert_stdout(p, &["20"]);
+    assert_stdout(match_program(1), &["10"]);
+}
+
+#[test]
+fn match_int_default() {
+    assert_stdout(match_program(7), &["0"]);
+}
@@ -0,0 +1,31 @@
+use crate::*;
+
+/// An uninitialized `MaybeUninit<u8>` can be copied around.
+#[test]
+fn copy_uninit() {
+    let pty = ptype(maybe_uninit_ty(<u8>::get_type()), align(1));
+    let locals = [pty, pty];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), uninit(<u8>::get_type())),
+        assign(local(1), load(local(0))),
+    ];
+    assert_stop(small_program(&locals, &stmts));
+}
+
+/// After writing the value field, it can be read back.
+#[test]
+fn init_later() {
+    let pty = ptype(maybe_uninit_ty(<bool>::get_type()), align(1));
+    let locals = [pty];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), uninit(<bool>::get_type())),
+        assign(field(local(0), 1), const_bool(true)),
+        print(load(field(local(0), 1)), 1)
+    );
+    let b1 = block!( exit() );
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_stdout(program(&[f]), &["true"]);
+}
@@ -0,0 +1,23 @@
+use crate::*;
+
+/// `min` and `max` order by the value of the integer, so signedness matters.
+#[test]
+fn min_max_signedness() {
+    let b0 = block!( print(imax::<i8>(const_int::<i8>(-1), const_int::<i8>(1)), 1) );
+    let b1 = block!( print(imax::<u8>(const_int::<u8>(255), const_int::<u8>(1)), 2) );
+    let b2 = block!( print(imin::<i8>(const_int::<i8>(-1), const_int::<i8>(1)), 3) );
+    let b3 = block!( print(imin::<u8>(const_int::<u8>(255), const_int::<u8>(1)), 4) );
+    let b4 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2, b3, b4]);
+    assert_stdout(program(&[f]), &["1", "255", "-1", "1"]);
+}
+
+#[test]
+fn min_max_fmt() {
+    let b0 = block!( print(imin::<u8>(const_int::<u8>(2), add::<u8>(const_int::<u8>(1), const_int::<u8>(1))), 1) );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    assert!(fmt_program(program(&[f])).contains("min<u8>(2_u8, 1_u8 +<u8> 1_u8)"));
+}
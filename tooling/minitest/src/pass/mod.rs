@@ -4,3 +4,61 @@ mod zst_tuple2;
 mod zst_array;
 mod dynamic_memory;
 mod concurrency;
+mod rwlock;
+mod condvar;
+mod exit_code;
+mod transmute;
+mod thread_local;
+mod thread_id;
+mod step_hook;
+mod ptr_offset;
+mod compare_exchange;
+mod deterministic;
+mod enum_variant;
+mod enum_niche;
+mod fmt_options;
+mod fmt_comptypes;
+mod finalize_log;
+mod abort;
+mod print_output;
+mod fmt_pointers;
+mod match_int;
+mod nested_array;
+mod global_init;
+mod print_str;
+mod typed_access;
+mod ptr_eq;
+mod union_reinterpret;
+mod target32;
+mod div_signed;
+mod stack_overflow;
+mod fmt_termination;
+mod alloc_size;
+mod reallocate;
+mod while_loop;
+mod fn_ptr_call;
+mod unwind;
+mod min_max;
+mod abs;
+mod int_cast;
+mod alignment_check;
+mod program_start;
+mod maybe_uninit;
+mod write_bytes;
+mod assert;
+mod fmt_index;
+mod addr_of_field;
+mod fmt_int_literals;
+mod align_offset;
+mod diverging_call;
+mod locals_macro;
+mod nested_typeconv;
+mod ptr_typeconv;
+mod intrinsic_stats;
+mod become_;
+mod spawn_arg;
+mod detached;
+mod zst_access;
+mod branch_hint;
+mod black_box;
+mod explore_all;
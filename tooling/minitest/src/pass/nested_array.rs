@@ -0,0 +1,50 @@
+use crate::*;
+
+/// Stores `[[1, 2], [3, 4], [5, 6]]` in a local and prints element `[2][1]`.
+#[test]
+fn nested_array_element() {
+    let row_ty = <[i32; 2]>::get_type();
+    let row = |a, b| array(&[const_int::<i32>(a), const_int::<i32>(b)], <i32>::get_type());
+    let value = array(&[row(1, 2), row(3, 4), row(5, 6)], row_ty);
+
+    let locals = [<[[i32; 2]; 3]>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), value),
+        print(load(index(index(local(0), const_int::<usize>(2)), const_int::<usize>(1))), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    dump_program(p);
+    assert_stdout(p, &["6"]);
+}
+
+#[test]
+fn tuple_fields() {
+    let value = tuple(&[(const_int::<u8>(1), size(0)), (const_int::<u32>(2), size(4))], <(u8, u32)>::get_type());
+
+    let locals = [<(u8, u32)>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), value),
+        print(load(field(local(0), 1)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_stdout(program(&[f]), &["2"]);
+}
+
+#[test]
+#[should_panic(expected = "array element 1 has the wrong type")]
+fn array_wrong_elem_type() {
+    array(&[const_int::<i32>(1), const_int::<u32>(2)], <i32>::get_type());
+}
+
+#[test]
+#[should_panic(expected = "tuple field 1 has the wrong offset")]
+fn tuple_wrong_offset() {
+    tuple(&[(const_int::<u8>(1), size(0)), (const_int::<u32>(2), size(1))], <(u8, u32)>::get_type());
+}
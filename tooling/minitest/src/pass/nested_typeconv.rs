@@ -0,0 +1,41 @@
+use crate::*;
+
+type Mem = BasicMemory<DefaultTarget>;
+
+#[test]
+fn nested_array_of_tuples() {
+    let pty = <[(u8, u32); 3]>::get_ptype();
+
+    let elem = tuple_ty(&[(size(0), <u8>::get_type()), (size(4), <u32>::get_type())], size(8));
+    assert_eq!(pty, ptype(array_ty(elem, 3), align(4)));
+    assert_eq!(pty.layout::<Mem>().size, size(24));
+    assert_eq!(pty.layout::<Mem>().size.bytes(), Int::from(std::mem::size_of::<[(u8, u32); 3]>()));
+    assert_eq!(pty.align.bytes(), Int::from(std::mem::align_of::<[(u8, u32); 3]>()));
+}
+
+#[test]
+fn nested_tuple_of_tuple_and_array() {
+    let pty = <((u8, u16), [u64; 2])>::get_ptype();
+
+    let inner = tuple_ty(&[(size(0), <u8>::get_type()), (size(2), <u16>::get_type())], size(4));
+    let expected = tuple_ty(&[(size(0), inner), (size(8), <[u64; 2]>::get_type())], size(24));
+    assert_eq!(pty, ptype(expected, align(8)));
+    assert_eq!(pty.layout::<Mem>().size, size(24));
+}
+
+// Writes the `u32` of the last element of a `[(u8, u32); 3]` and reads it back.
+#[test]
+fn nested_typeconv_access() {
+    let locals = locals!(arr: [(u8, u32); 3]);
+
+    let b0 = block!(
+        storage_live(0),
+        assign(field(index(local(0), const_int::<usize>(2)), 1), const_int::<u32>(42)),
+        print(load(field(index(local(0), const_int::<usize>(2)), 1)), 1)
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_stdout(p, &["42"]);
+}
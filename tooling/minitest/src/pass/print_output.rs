@@ -0,0 +1,80 @@
+use crate::*;
+
+/// Prints 1 and 2 to stdout and 3 to stderr.
+fn print_both() -> Program {
+    let b0 = block!( print(const_int::<u32>(1), 1) );
+    let b1 = block!( eprint(const_int::<u32>(3), 2) );
+    let b2 = block!( print(const_int::<u32>(2), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn stdout_lines() {
+    assert_stdout(print_both(), &["1", "2"]);
+}
+
+#[test]
+fn stderr_lines() {
+    assert_stderr(print_both(), &["3"]);
+}
+
+#[test]
+fn no_output() {
+    let p = small_program(&[], &[]);
+    assert_stdout(p, &[]);
+    assert_stderr(p, &[]);
+}
+
+#[test]
+#[should_panic(expected = "stdout does not match the expected output")]
+fn stdout_mismatch() {
+    assert_stdout(print_both(), &["1", "3"]);
+}
+
+#[test]
+fn run_expect_ok_lines() {
+    assert_eq!(run_expect_ok(print_both()), ["1", "2"]);
+}
+
+#[test]
+#[should_panic(expected = "program did not terminate correctly: UB: reached unreachable code")]
+fn run_expect_ok_ub() {
+    let b0 = block!( unreachable() );
+    let f = function(Ret::No, 0, &[], &[b0]);
+    run_expect_ok(program(&[f]));
+}
+
+#[test]
+fn print_many_in_order() {
+    let locals = locals!(x: u32);
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_int::<u32>(7)),
+        goto(1),
+    );
+    // The prints occupy blocks 1 to 3.
+    let prints = print_many(&[const_int::<u32>(1), load(local(0)), const_bool(true)], 1, 4);
+    let b4 = block!( exit() );
+
+    let mut blocks = vec![b0];
+    blocks.extend(prints);
+    blocks.push(b4);
+
+    let f = function(Ret::No, 0, &locals, &blocks);
+    assert_stdout(program(&[f]), &["1", "7", "true"]);
+}
+
+#[test]
+fn eprint_many_in_order() {
+    let mut blocks = eprint_many(&[const_int::<u32>(3), const_int::<u32>(4)], 0, 2);
+    blocks.push(block!( exit() ));
+
+    let f = function(Ret::No, 0, &[], &blocks);
+    let p = program(&[f]);
+    assert_stdout(p, &[]);
+    assert_stderr(p, &["3", "4"]);
+}
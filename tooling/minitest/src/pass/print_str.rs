@@ -0,0 +1,46 @@
+use crate::*;
+
+/// Stores the bytes of `s` in local(0) and prints them as a string.
+fn print_bytes(s: &str) -> Program {
+    let n = s.len();
+    let locals = [array_ty(<u8>::get_type(), n)];
+    let bytes: Vec<_> = s.bytes().map(const_int::<u8>).collect();
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_array(&bytes, <u8>::get_type())),
+        print(str_ref(ptr, const_int::<usize>(n)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals.map(|ty| ptype(ty, align(1))), &[b0, b1]);
+    program(&[f])
+}
+
+#[test]
+fn print_hello() {
+    assert_stdout(print_bytes("hello"), &["hello"]);
+}
+
+#[test]
+fn print_utf8() {
+    assert_stdout(print_bytes("grüße"), &["grüße"]);
+}
+
+#[test]
+fn print_on_target32() {
+    let locals = [<[u8; 2]>::get_ptype()];
+    let bytes = [const_int::<u8>(b'h'), const_int::<u8>(b'i')];
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_array(&bytes, <u8>::get_type())),
+        print(str_ref_on::<Target32>(ptr, const_usize_on::<Target32>(2)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_stdout_on::<Target32>(program(&[f]), &["hi"]);
+}
@@ -0,0 +1,22 @@
+use crate::*;
+
+/// The start function is the second function, and calls the first one.
+#[test]
+fn start_is_second_function() {
+    let locals = [<()>::get_ptype()];
+    let b0 = block!( print(const_int::<u32>(1), 1) );
+    let b1 = block!( return_() );
+    let helper = function(Ret::Yes, 0, &locals, &[b0, b1]);
+
+    let b0 = block!(
+        storage_live(0),
+        call(0, &[], Some(local(0)), Some(1)),
+    );
+    let b1 = block!( print(const_int::<u32>(2), 2) );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let p = program_with_start(&[helper, main], 1);
+    assert_eq!(p.start, FnName(Name::from_internal(1)));
+    assert_stdout(p, &["1", "2"]);
+}
@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Prints whether `p == p`, `p == p + 1` and `p == null`, for `p` pointing to local(0).
+#[test]
+fn ptr_comparisons() {
+    let locals = [<[u8; 2]>::get_ptype()];
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+    let offset_ptr = ptr_offset(ptr, const_int::<usize>(1), InBounds::Yes);
+
+    let b0 = block!(
+        storage_live(0),
+        print(ptr_eq(ptr, ptr), 1),
+    );
+    let b1 = block!( print(ptr_eq(ptr, offset_ptr), 2) );
+    let b2 = block!( print(ptr_ne(ptr, offset_ptr), 3) );
+    let b3 = block!( print(is_null(ptr), 4) );
+    let b4 = block!( print(is_null(transmute(const_int::<usize>(0), <*const u8>::get_type())), 5) );
+    let b5 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+    let p = program(&[f]);
+    dump_program(p);
+    assert_stdout(p, &["true", "false", "true", "false", "true"]);
+}
+
+/// Only the address is compared: a pointer without provenance equals the original.
+#[test]
+fn ptr_eq_ignores_provenance() {
+    let locals = [<u32>::get_ptype()];
+    let ptr = addr_of(local(0), <*const u32>::get_type());
+    let roundtrip = transmute(ptr_to_int(ptr), <*const u32>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        print(ptr_eq(ptr, roundtrip), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_stdout(program(&[f]), &["true"]);
+}
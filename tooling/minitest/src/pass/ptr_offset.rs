@@ -0,0 +1,53 @@
+use crate::*;
+
+/// Sums up an array by walking a pointer across it with inbounds offsets.
+#[test]
+fn walk_array() {
+    // local(0): the array, local(1): the pointer, local(2): the counter, local(3): the sum.
+    let locals = [<[i32; 4]>::get_ptype(), <*const i32>::get_ptype(), <usize>::get_ptype(), <i32>::get_ptype()];
+
+    let elems: Vec<_> = (1..=4).map(const_int::<i32>).collect();
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        storage_live(3),
+        assign(local(0), const_array(&elems, <i32>::get_type())),
+        assign(local(1), addr_of(local(0), <*const i32>::get_type())),
+        assign(local(2), const_int::<usize>(0)),
+        assign(local(3), const_int::<i32>(0)),
+        goto(1)
+    );
+    let b1 = block!( if_(lt(load(local(2)), const_int::<usize>(4)), 2, 3) );
+    let b2 = block!(
+        assign(local(3), add::<i32>(load(local(3)), load(deref(load(local(1)), <i32>::get_ptype())))),
+        // This moves the pointer one past the end of the array in the last iteration, which is allowed.
+        assign(local(1), offset_inbounds(load(local(1)), const_int::<usize>(4))),
+        assign(local(2), add::<usize>(load(local(2)), const_int::<usize>(1))),
+        goto(1)
+    );
+    let b3 = block!( print(load(local(3)), 4) );
+    let b4 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    let p = program(&[f]);
+    assert_stdout(p, &["10"]);
+}
+
+/// Wrapping offsets may leave the allocation, as long as the result is not used for an access.
+#[test]
+fn wrapping_out_of_bounds() {
+    let locals = [<i32>::get_ptype(), <*const i32>::get_ptype()];
+
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), <*const i32>::get_type())),
+        assign(local(1), offset_wrapping(load(local(1)), const_int::<usize>(64))),
+        assign(local(1), offset_wrapping(load(local(1)), const_int::<isize>(-64))),
+        assign(local(0), const_int::<i32>(7)),
+        assign(local(0), load(deref(load(local(1)), <i32>::get_ptype()))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
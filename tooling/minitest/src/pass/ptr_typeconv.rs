@@ -0,0 +1,30 @@
+use crate::*;
+
+#[test]
+fn ptr_typeconv_types() {
+    let pointee = <i32>::get_layout();
+    assert_eq!(<*mut i32>::get_ptype(), ptype(raw_ptr_ty(pointee), DefaultTarget::PTR_ALIGN));
+    assert_eq!(<*const i32>::get_ptype(), ptype(raw_ptr_ty(pointee), DefaultTarget::PTR_ALIGN));
+    assert_eq!(<&i32>::get_ptype(), ptype(ref_ty(pointee), DefaultTarget::PTR_ALIGN));
+    assert_eq!(<&mut i32>::get_ptype(), ptype(ref_mut_ty(pointee), DefaultTarget::PTR_ALIGN));
+}
+
+// Writes a value through a `*mut i32` local and reads it back, both directly and through the pointer.
+#[test]
+fn ptr_typeconv_round_trip() {
+    let locals = locals!(x: i32, p: *mut i32);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), <*mut i32>::get_type())),
+        assign(deref(load(local(1)), <i32>::get_ptype()), const_int::<i32>(-7)),
+        print(load(local(0)), 1)
+    );
+    let b1 = block!(print(load(deref(load(local(1)), <i32>::get_ptype())), 2));
+    let b2 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = program(&[f]);
+    assert_stdout(p, &["-7", "-7"]);
+}
@@ -0,0 +1,30 @@
+use crate::*;
+
+/// Stores 42 in a 4-byte allocation, grows it to 8 bytes and stores 7 in the new part.
+#[test]
+fn reallocate_grow() {
+    let locals = [<*mut u32>::get_ptype()];
+    let first = deref(load(local(0)), <u32>::get_ptype());
+    let second = deref(ptr_offset(load(local(0)), const_int::<usize>(4), InBounds::Yes), <u32>::get_ptype());
+
+    let b0 = block!(
+        storage_live(0),
+        allocate(const_int::<usize>(4), const_int::<usize>(4), local(0), 1),
+    );
+    let b1 = block!(
+        assign(first, const_int::<u32>(42)),
+        reallocate(load(local(0)), const_int::<usize>(4), const_int::<usize>(8), const_int::<usize>(4), local(0), 2),
+    );
+    let b2 = block!(
+        assign(second, const_int::<u32>(7)),
+        print(load(first), 3),
+    );
+    let b3 = block!( print(load(second), 4) );
+    let b4 = block!( deallocate(load(local(0)), const_int::<usize>(8), const_int::<usize>(4), 5) );
+    let b5 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+    let p = program(&[f]);
+    dump_program(p);
+    assert_stdout(p, &["42", "7"]);
+}
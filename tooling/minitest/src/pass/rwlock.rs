@@ -0,0 +1,102 @@
+use crate::*;
+
+/// A function that increments global(1) while holding the rwlock stored in global(0) for writing.
+fn writer() -> Function {
+    let b0 = block!(
+        acquire_write(load(global::<u32>(0)), 1)
+    );
+    let b1 = block!(
+        assign(global::<u32>(1), add::<u32>(load(global::<u32>(1)), const_int::<u32>(1))),
+        release_write(load(global::<u32>(0)), 2)
+    );
+    let b2 = block!(return_());
+
+    function(Ret::No, 0, &[], &[b0, b1, b2])
+}
+
+/// A function that reads global(1) while holding the rwlock stored in global(0) for reading.
+fn reader() -> Function {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        acquire_read(load(global::<u32>(0)), 1)
+    );
+    let b1 = block!(
+        assign(local(0), load(global::<u32>(1))),
+        release_read(load(global::<u32>(0)), 2)
+    );
+    let b2 = block!(return_());
+
+    function(Ret::No, 0, &locals, &[b0, b1, b2])
+}
+
+/// Two writers and two readers access global(1) non-atomically.
+/// Since the writers are mutually exclusive with everyone else, this can never be a data race.
+#[test]
+fn rwlock_excludes_writers() {
+    // The locals are used to store the thread ids.
+    let locals = [<u32>::get_ptype(); 4];
+
+    let b0 = block!(
+        create_rwlock(global::<u32>(0), 1)
+    );
+    let b1 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 2)
+    );
+    let b2 = block!(
+        storage_live(1),
+        spawn(fn_ptr(1), Some(local(1)), 3)
+    );
+    let b3 = block!(
+        storage_live(2),
+        spawn(fn_ptr(2), Some(local(2)), 4)
+    );
+    let b4 = block!(
+        storage_live(3),
+        spawn(fn_ptr(2), Some(local(3)), 5)
+    );
+    let b5 = block!( join(load(local(0)), 6) );
+    let b6 = block!( join(load(local(1)), 7) );
+    let b7 = block!( join(load(local(2)), 8) );
+    let b8 = block!( join(load(local(3)), 9) );
+    let b9 = block!( print(load(global::<u32>(1)), 10) );
+    let b10 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, b10]);
+
+    // global(0) is used as a rwlock. We store the rwlock id there.
+    // global(1) is the value that is read and written.
+    let globals = [global_int::<u32>(), global_int::<u32>()];
+
+    let p = program_with_globals(&[main, writer(), reader()], &globals);
+
+    assert!(!has_data_race(p));
+    assert_stdout(p, &["2"]);
+}
+
+/// The main thread holds the rwlock for reading while it waits for another reader.
+/// This would deadlock if readers could not share the lock.
+#[test]
+fn rwlock_shared_readers() {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!( create_rwlock(global::<u32>(0), 1) );
+    let b1 = block!( acquire_read(load(global::<u32>(0)), 2) );
+    let b2 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 3)
+    );
+    let b3 = block!( join(load(local(0)), 4) );
+    let b4 = block!( release_read(load(global::<u32>(0)), 5) );
+    let b5 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    let globals = [global_int::<u32>(), global_int::<u32>()];
+
+    let p = program_with_globals(&[main, reader()], &globals);
+
+    assert_stop(p);
+}
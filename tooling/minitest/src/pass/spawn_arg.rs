@@ -0,0 +1,46 @@
+use crate::*;
+
+/// Sums up `1..=n` for its argument `n` and returns the sum.
+fn sum_to() -> Function {
+    let locals = locals!(ret: u32, n: u32);
+    let sum = local(0);
+    let n = local(1);
+
+    let b0 = block!(
+        assign(sum, const_int::<u32>(0)),
+        goto(1),
+    );
+    // The loop occupies blocks 1 and 2.
+    let loop_blocks = while_loop(gt(load(n), const_int::<u32>(0)), |_, header| vec![
+        block!(
+            assign(sum, add::<u32>(load(sum), load(n))),
+            assign(n, sub::<u32>(load(n), const_int::<u32>(1))),
+            goto(header),
+        ),
+    ], 1, 3);
+    let b3 = block!( return_() );
+
+    let mut blocks = vec![b0];
+    blocks.extend(loop_blocks);
+    blocks.push(b3);
+    function(Ret::Yes, 1, &locals, &blocks)
+}
+
+#[test]
+fn spawn_arg_join_value() {
+    let locals = locals!(thread: u32, result: u32);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        spawn_with_arg(fn_ptr(1), const_int::<u32>(10), local(0), 1),
+    );
+    let b1 = block!( join_with_ret(load(local(0)), local(1), 2) );
+    let b2 = block!( print(load(local(1)), 3) );
+    let b3 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = program(&[main, sum_to()]);
+    assert!(fmt_program(p).contains("_0 = spawn(f1, 10_u32) -> bb1;"));
+    assert_stdout(p, &["55"]);
+}
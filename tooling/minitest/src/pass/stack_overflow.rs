@@ -0,0 +1,50 @@
+use crate::*;
+
+/// Function 1 calls itself with `n - 1` until `n` is 0, starting with `n`.
+fn countdown(n: u32) -> Program {
+    let locals = [<()>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        call(1, &[const_int::<u32>(n)], Some(local(0)), Some(1)),
+    );
+    let b1 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    let locals = [<()>::get_ptype(), <u32>::get_ptype(), <()>::get_ptype()];
+    let b0 = block!(
+        storage_live(2),
+        if_(eq(load(local(1)), const_int::<u32>(0)), 2, 1),
+    );
+    let b1 = block!( call(1, &[sub::<u32>(load(local(1)), const_int::<u32>(1))], Some(local(2)), Some(2)) );
+    let b2 = block!( return_() );
+    let f = function(Ret::Yes, 1, &locals, &[b0, b1, b2]);
+
+    program(&[main, f])
+}
+
+#[test]
+fn recursion_without_base_case() {
+    let b0 = block!( call(1, &[], None, Some(1)) );
+    let b1 = block!( exit() );
+    let main = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let b0 = block!( call(1, &[], None, Some(1)) );
+    let b1 = block!( return_() );
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+
+    assert_eq!(run_program(program(&[main, f])), TerminationInfo::StackOverflow);
+}
+
+#[test]
+fn max_stack_depth_is_configurable() {
+    // `main` and 11 frames of function 1.
+    assert_eq!(run_program_with_max_stack_depth(countdown(10), 12), TerminationInfo::MachineStop { code: Int::ZERO });
+    assert_eq!(run_program_with_max_stack_depth(countdown(10), 11), TerminationInfo::StackOverflow);
+}
+
+#[test]
+fn max_stack_depth_can_be_lifted() {
+    // `main` and `DEFAULT_MAX_STACK_DEPTH + 1` frames of function 1.
+    let config = RunConfig { max_stack_depth: None, ..RunConfig::default() };
+    assert_eq!(run_program_with_config(countdown(DEFAULT_MAX_STACK_DEPTH), config), TerminationInfo::MachineStop { code: Int::ZERO });
+}
@@ -0,0 +1,95 @@
+use crate::*;
+
+use std::ops::ControlFlow;
+
+fn straight_line() -> Program {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_int::<u32>(1)),
+        goto(1)
+    );
+    let b1 = block!(
+        assign(local(0), const_int::<u32>(2)),
+        exit()
+    );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    program(&[f])
+}
+
+/// The hook sees every statement and terminator exactly once, in order.
+#[test]
+fn step_hook_counts_steps() {
+    let mut steps = Vec::new();
+    let info = run_program_with_hook(straight_line(), |info| {
+        steps.push((info.block.0.get_internal(), info.stmt.try_to_usize().unwrap()));
+        ControlFlow::Continue(())
+    });
+
+    assert_eq!(info, Some(TerminationInfo::MachineStop { code: Int::ZERO }));
+    // 3 statements and 2 terminators.
+    assert_eq!(steps, [(0, 0), (0, 1), (0, 2), (1, 0), (1, 1)]);
+}
+
+/// The hook can stop execution before the program finishes.
+#[test]
+fn step_hook_stops_early() {
+    let mut count = 0;
+    let info = run_program_with_hook(straight_line(), |_| {
+        if count == 2 {
+            return ControlFlow::Break(());
+        }
+        count += 1;
+        ControlFlow::Continue(())
+    });
+
+    assert_eq!(info, None);
+    assert_eq!(count, 2);
+}
+
+/// After writing the second field of a tuple, only that field is initialized.
+#[test]
+fn step_hook_init_mask() {
+    let locals = [<(u8, u32)>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(field(local(0), 1), const_int::<u32>(7)),
+    ];
+    let p = small_program(&locals, &stmts);
+
+    let mut mask = None;
+    run_program_with_hook(p, |info| {
+        // Before the `exit`, the last access is the field write.
+        if info.stmt == Int::from(2) {
+            let (addr, _) = info.last_access().unwrap();
+            mask = info.is_initialized(addr - 4, Size::from_bytes_const(8));
+            return ControlFlow::Break(());
+        }
+        ControlFlow::Continue(())
+    });
+
+    let mask = mask.unwrap();
+    assert_eq!(mask, [false, false, false, false, true, true, true, true]);
+    assert_eq!(fmt_init_mask(&mask), "uuuu....");
+}
+
+/// The hook reports the name of the function that was called, even if another function has the same body.
+#[test]
+fn step_hook_identical_functions() {
+    let locals = [<()>::get_ptype()];
+    let f0 = function(Ret::No, 0, &locals, &[
+        block!( storage_live(0), call(2, &[], Some(local(0)), Some(1)) ),
+        block!( exit() ),
+    ]);
+    let callee = function(Ret::Yes, 0, &locals, &[block!( return_() )]);
+
+    let mut funcs = Vec::new();
+    run_program_with_hook(program(&[f0, callee, callee]), |info| {
+        funcs.push(info.func.0.get_internal());
+        ControlFlow::Continue(())
+    });
+
+    assert_eq!(funcs, [0, 0, 2, 0]);
+}
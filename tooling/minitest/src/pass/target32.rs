@@ -0,0 +1,88 @@
+use crate::*;
+
+#[test]
+fn usize_size() {
+    assert_eq!(usize_ty::<Target32>().size::<BasicMemory<Target32>>(), size(4));
+    assert_eq!(<usize>::get_type().size::<BasicMemory<DefaultTarget>>(), size(8));
+    assert_eq!(<*const u8>::get_type().size::<BasicMemory<Target32>>(), size(4));
+    assert_eq!(<*const u8>::get_type().size::<BasicMemory<DefaultTarget>>(), size(8));
+}
+
+/// Stores the address of local(0) into a 4-byte `usize`, casts it back to a pointer,
+/// and reads local(0) through that pointer.
+fn ptr2int_32() -> Program {
+    let usize_32 = ptype(usize_ty::<Target32>(), align(4));
+    let locals = [<u8>::get_ptype(), usize_32];
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+    let back = int_to_ptr(load(local(1)), <*const u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<u8>(42)),
+        assign(local(1), ptr_to_int(ptr)),
+        print(load(deref(back, <u8>::get_ptype())), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    program(&[f])
+}
+
+#[test]
+fn ptr2int_on_target32() {
+    assert_stdout_on::<Target32>(ptr2int_32(), &["42"]);
+}
+
+/// On the default target, the address is 8 bytes and does not fit the 4-byte local.
+#[test]
+fn ptr2int_32_ill_formed_on_default() {
+    assert!(check_program_on::<Target32>(ptr2int_32()).is_ok());
+    assert!(check_program(ptr2int_32()).is_err());
+}
+
+/// An address that does not fit into 32 bits cannot even be written down on the 32-bit target.
+#[test]
+fn address_beyond_32_bits() {
+    let addr_program = |addr: u64| {
+        let locals = [<*const u8>::get_ptype()];
+        let stmts = [
+            storage_live(0),
+            assign(local(0), int_to_ptr_invalid(const_usize_on::<Target32>(addr), <*const u8>::get_type())),
+        ];
+        small_program(&locals, &stmts)
+    };
+    assert!(check_program_on::<Target32>(addr_program((1 << 32) - 1)).is_ok());
+    assert_eq!(
+        check_program_on::<Target32>(addr_program(1 << 32)).unwrap_err().msg.get_internal(),
+        "integer constant is out of bounds for its type"
+    );
+}
+
+/// global(1) is a 4-byte pointer to global(0); it is not null and reads back the value.
+#[test]
+fn global_pointer_on_target32() {
+    let globals = [
+        global_int_init_on::<Target32>(usize_ty::<Target32>(), 7),
+        global_pointer_on::<Target32>(0, size(0)),
+    ];
+    let usize_32 = ptype(usize_ty::<Target32>(), align(4));
+    let ptr_ty = <*const u8>::get_type();
+    let ptr = load(global_on::<Target32>(1, ptype(ptr_ty, Target32::PTR_ALIGN)));
+
+    let b0 = block!(
+        print(is_null_on::<Target32>(ptr), 1),
+    );
+    let b1 = block!(
+        print(load(deref(ptr, usize_32)), 2),
+    );
+    let b2 = block!(
+        print(ptr_eq(null_ptr_on::<Target32>(ptr_ty), dangling_ptr_on::<Target32>(ptr_ty, align(1))), 3),
+    );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2, b3]);
+    let p = program_with_globals(&[f], &globals);
+    assert!(check_program_on::<Target32>(p).is_ok());
+    assert_stdout_on::<Target32>(p, &["false", "7", "false"]);
+}
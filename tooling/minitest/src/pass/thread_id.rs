@@ -0,0 +1,64 @@
+use crate::*;
+
+/// Every thread prints its own id, the main thread then prints the ids returned by `spawn`.
+#[test]
+fn thread_ids_distinct() {
+    fn print_id() -> Function {
+        let locals = [<usize>::get_ptype()];
+
+        let b0 = block!(
+            storage_live(0),
+            thread_id(local(0), 1)
+        );
+        let b1 = block!( print(load(local(0)), 2) );
+        let b2 = block!( return_() );
+
+        function(Ret::No, 0, &locals, &[b0, b1, b2])
+    }
+
+    // local(0) stores the id of the main thread, the others store the spawned thread ids.
+    let locals = [<usize>::get_ptype(); 4];
+
+    let b0 = block!(
+        storage_live(0),
+        thread_id(local(0), 1)
+    );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!(
+        storage_live(1),
+        spawn(fn_ptr(1), Some(local(1)), 3)
+    );
+    let b3 = block!(
+        storage_live(2),
+        spawn(fn_ptr(1), Some(local(2)), 4)
+    );
+    let b4 = block!(
+        storage_live(3),
+        spawn(fn_ptr(1), Some(local(3)), 5)
+    );
+    let b5 = block!( join(load(local(1)), 6) );
+    let b6 = block!( join(load(local(2)), 7) );
+    let b7 = block!( join(load(local(3)), 8) );
+    let b8 = block!( print(load(local(1)), 9) );
+    let b9 = block!( print(load(local(2)), 10) );
+    let b10 = block!( print(load(local(3)), 11) );
+    let b11 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6, b7, b8, b9, b10, b11]);
+    let p = program(&[main, print_id()]);
+
+    let out = get_stdout(p).unwrap();
+
+    // The main thread always has id 0.
+    assert_eq!(out[0], "0");
+
+    let mut printed = out[1..4].to_vec();
+    let mut spawned = out[4..7].to_vec();
+    printed.sort();
+    spawned.sort();
+    spawned.dedup();
+
+    assert_eq!(spawned.len(), 3);
+    assert!(!spawned.contains(&out[0]));
+    assert_eq!(printed, spawned);
+}
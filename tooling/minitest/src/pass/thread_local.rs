@@ -0,0 +1,81 @@
+use crate::*;
+
+/// Two threads write distinct values to the same thread-local global.
+/// Each of them must read back its own value, and the main thread's instance stays untouched.
+#[test]
+fn thread_local_instances() {
+    // Writes `val` to the thread-local global(0) and prints it after the other thread had a chance to write.
+    fn writer(val: u32) -> Function {
+        let b0 = block!(
+            assign(global::<u32>(0), const_int::<u32>(val)),
+            goto(1)
+        );
+        let b1 = block!( print(load(global::<u32>(0)), 2) );
+        let b2 = block!( return_() );
+
+        function(Ret::No, 0, &[], &[b0, b1, b2])
+    }
+
+    // The locals are used to store the thread ids.
+    let locals = [<u32>::get_ptype(); 2];
+
+    let b0 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 1)
+    );
+    let b1 = block!(
+        storage_live(1),
+        spawn(fn_ptr(2), Some(local(1)), 2)
+    );
+    let b2 = block!( join(load(local(0)), 3) );
+    let b3 = block!( join(load(local(1)), 4) );
+    let b4 = block!( print(load(global::<u32>(0)), 5) );
+    let b5 = block!( exit() );
+
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5]);
+
+    let globals = [thread_local_int::<u32>()];
+
+    let p = program_with_globals(&[main, writer(1), writer(2)], &globals);
+
+    for _ in 0..20 {
+        let out = run_expect_ok(p);
+
+        // The threads print in some order, the main thread prints last.
+        let mut threads = [out[0].clone(), out[1].clone()];
+        threads.sort();
+        assert_eq!(threads, ["1", "2"]);
+        assert_eq!(out[2], "0");
+    }
+}
+
+/// The thread-local global(1) points to the thread-local global(0).
+/// Each thread's instance of global(1) points to that thread's instance of global(0).
+#[test]
+fn thread_local_relocation() {
+    let through_ptr = deref(load(global::<*mut u32>(1)), <u32>::get_ptype());
+
+    let b0 = block!(
+        assign(through_ptr, const_int::<u32>(7)),
+        print(load(global::<u32>(0)), 1),
+    );
+    let b1 = block!( return_() );
+    let thread = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let locals = locals!(thread: u32);
+    let b0 = block!(
+        storage_live(0),
+        assign(through_ptr, const_int::<u32>(5)),
+        spawn(fn_ptr(1), Some(local(0)), 1),
+    );
+    let b1 = block!( join(load(local(0)), 2) );
+    let b2 = block!( print(load(global::<u32>(0)), 3) );
+    let b3 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let mut pointer = global_pointer(0, size(0));
+    pointer.thread_local = true;
+    let globals = [thread_local_int::<u32>(), pointer];
+    let p = program_with_globals(&[main, thread], &globals);
+    assert_stdout(p, &["7", "5"]);
+}
@@ -0,0 +1,27 @@
+use crate::*;
+
+fn print_u32_bytes() -> Program {
+    let locals = [<[u8; 4]>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), transmute(const_int::<u32>(0x01020304), <[u8; 4]>::get_type())),
+        print(load(index(local(0), const_int::<usize>(0))), 1)
+    );
+    let b1 = block!( print(load(index(local(0), const_int::<usize>(3))), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f])
+}
+
+#[test]
+fn transmute_u32_to_array() {
+    // `DefaultTarget` is little-endian.
+    assert_stdout(print_u32_bytes(), &["4", "1"]);
+}
+
+#[test]
+fn transmute_u32_to_array_big_endian() {
+    assert_stdout_on::<BigEndianTarget>(print_u32_bytes(), &["1", "4"]);
+}
@@ -0,0 +1,43 @@
+use crate::*;
+
+/// Writes 42 through a pointer to local(0), reads it back into local(1) and prints it.
+#[test]
+fn typed_write_read() {
+    let locals = [<u32>::get_ptype(), <u32>::get_ptype()];
+    let ptr = addr_of(local(0), <*mut u32>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        typed_write(ptr, const_int::<u32>(42), <u32>::get_ptype(), 1),
+    );
+    let b1 = block!( typed_read(local(1), ptr, <u32>::get_ptype(), 2) );
+    let b2 = block!( print(load(local(1)), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let p = program(&[f]);
+    dump_program(p);
+    assert_stdout(p, &["42"]);
+}
+
+/// Reading a `u32` at offset 1 of a byte buffer is fine with alignment 1.
+#[test]
+fn typed_read_unaligned_ptype() {
+    let locals = [<[u8; 8]>::get_ptype(), <u32>::get_ptype()];
+    let bytes: Vec<_> = (0..8).map(const_int::<u8>).collect();
+    let ptr = ptr_offset(addr_of(local(0), <*const u8>::get_type()), const_int::<usize>(1), InBounds::Yes);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_array(&bytes, <u8>::get_type())),
+        typed_read(local(1), ptr, ptype(<u32>::get_type(), align(1)), 1),
+    );
+    let b1 = block!( print(load(local(1)), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    // Bytes 1, 2, 3, 4 in little-endian.
+    assert_stdout(program(&[f]), &["67305985"]);
+}
@@ -0,0 +1,41 @@
+use crate::*;
+
+/// Writing the `u32` field of a union and reading the `[u8; 4]` field yields the little-endian bytes.
+#[test]
+fn union_u32_as_bytes() {
+    let fields = [(size(0), <u32>::get_type()), (size(0), <[u8; 4]>::get_type())];
+    let union_ty = union_ty(&fields, size(4));
+
+    let locals = [union_ptype(&fields, size(4), align(4)), <[u8; 4]>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), union(0, const_int::<u32>(0x04030201), union_ty)),
+        assign(local(1), load(field(local(0), 1))),
+        print(load(index(local(1), const_int::<usize>(0))), 1),
+    );
+    let b1 = block!( print(load(index(local(1), const_int::<usize>(1))), 2) );
+    let b2 = block!( print(load(index(local(1), const_int::<usize>(2))), 3) );
+    let b3 = block!( print(load(index(local(1), const_int::<usize>(3))), 4) );
+    let b4 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    assert_stdout(program(&[f]), &["1", "2", "3", "4"]);
+}
+
+/// Writing one field in place and reading another also reinterprets the bytes.
+#[test]
+fn union_field_write_then_read() {
+    let fields = [(size(0), <[u8; 2]>::get_type()), (size(0), <u16>::get_type())];
+
+    let locals = [union_ptype(&fields, size(2), align(2))];
+    let b0 = block!(
+        storage_live(0),
+        assign(field(local(0), 0), const_array(&[const_int::<u8>(1), const_int::<u8>(1)], <u8>::get_type())),
+        print(load(field(local(0), 1)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_stdout(program(&[f]), &["257"]);
+}
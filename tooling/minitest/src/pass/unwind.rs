@@ -0,0 +1,79 @@
+use crate::*;
+
+/// Function 2 panics, function 1 calls it and has a cleanup block that prints `1`.
+fn panicking_fns() -> [Function; 2] {
+    let b0 = block!( call_unwind(2, &[], None, None, 1) );
+    let b1 = block!( print(const_int::<u32>(1), 2) );
+    let b2 = block!( resume() );
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2]);
+
+    let b0 = block!( panic() );
+    let g = function(Ret::No, 0, &[], &[b0]);
+
+    [f, g]
+}
+
+#[test]
+fn cleanup_runs_before_propagating() {
+    let b0 = block!( call_unwind(1, &[], None, None, 1) );
+    let b1 = block!( print(const_int::<u32>(2), 2) );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &[], &[b0, b1, b2]);
+
+    let [f, g] = panicking_fns();
+    assert_stdout(program(&[main, f, g]), &["1", "2"]);
+}
+
+#[test]
+fn unwinding_skips_calls_without_cleanup() {
+    // Function 3 calls function 1 without a cleanup block.
+    let b0 = block!( call_unwind(3, &[], None, None, 1) );
+    let b1 = block!( print(const_int::<u32>(2), 2) );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &[], &[b0, b1, b2]);
+
+    let b0 = block!( call_diverging(1, &[]) );
+    let h = function(Ret::No, 0, &[], &[b0]);
+
+    let [f, g] = panicking_fns();
+    assert_stdout(program(&[main, f, g, h]), &["1", "2"]);
+}
+
+#[test]
+fn uncaught_panic_aborts() {
+    let b0 = block!( call_diverging(1, &[]) );
+    let main = function(Ret::No, 0, &[], &[b0]);
+
+    let [f, g] = panicking_fns();
+    assert_abort(program(&[main, f, g]));
+}
+
+#[test]
+fn panic_in_main_aborts() {
+    let b0 = block!( panic() );
+    let main = function(Ret::No, 0, &[], &[b0]);
+
+    assert_abort(program(&[main]));
+}
+
+#[test]
+fn catch_unwind_recovers() {
+    // Function 1 panics after running its cleanup, function 3 returns normally.
+    let locals = [<bool>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        catch_unwind(1, local(0), 1),
+    );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!( catch_unwind(3, local(0), 3) );
+    let b3 = block!( print(load(local(0)), 4) );
+    let b4 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+
+    let locals = [<()>::get_ptype()];
+    let b0 = block!( return_() );
+    let returns = function(Ret::Yes, 0, &locals, &[b0]);
+
+    let [f, g] = panicking_fns();
+    assert_stdout(program(&[main, f, g, returns]), &["1", "true", "false"]);
+}
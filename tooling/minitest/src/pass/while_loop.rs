@@ -0,0 +1,65 @@
+use crate::*;
+
+/// Sums 0..10 into local(1) and prints it.
+#[test]
+fn while_loop_sum() {
+    let locals = [<u32>::get_ptype(), <u32>::get_ptype()];
+    let i = local(0);
+    let sum = local(1);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(i, const_int::<u32>(0)),
+        assign(sum, const_int::<u32>(0)),
+        goto(1),
+    );
+    // The loop occupies blocks 1 and 2.
+    let loop_blocks = while_loop(lt(load(i), const_int::<u32>(10)), |_, header| vec![
+        block!(
+            assign(sum, add::<u32>(load(sum), load(i))),
+            assign(i, add::<u32>(load(i), const_int::<u32>(1))),
+            goto(header),
+        ),
+    ], 1, 3);
+    let b3 = block!( print(load(sum), 4) );
+    let b4 = block!( exit() );
+
+    let mut blocks = vec![b0];
+    blocks.extend(loop_blocks);
+    blocks.extend([b3, b4]);
+
+    let f = function(Ret::No, 0, &locals, &blocks);
+    let p = program(&[f]);
+    dump_program(p);
+    assert_stdout(p, &["45"]);
+}
+
+/// A body spanning several blocks: prints 0, 1, 2.
+#[test]
+fn while_loop_multi_block_body() {
+    let locals = [<u32>::get_ptype()];
+    let i = local(0);
+
+    let b0 = block!(
+        storage_live(0),
+        assign(i, const_int::<u32>(0)),
+        goto(1),
+    );
+    // The loop occupies blocks 1 to 3.
+    let loop_blocks = while_loop(lt(load(i), const_int::<u32>(3)), |start, header| vec![
+        block!( print(load(i), start + 1) ),
+        block!(
+            assign(i, add::<u32>(load(i), const_int::<u32>(1))),
+            goto(header),
+        ),
+    ], 1, 4);
+    let b4 = block!( exit() );
+
+    let mut blocks = vec![b0];
+    blocks.extend(loop_blocks);
+    blocks.push(b4);
+
+    let f = function(Ret::No, 0, &locals, &blocks);
+    assert_stdout(program(&[f]), &["0", "1", "2"]);
+}
@@ -0,0 +1,37 @@
+use crate::*;
+
+/// Zeroes a `[u32; 4]` buffer and reads back an element.
+#[test]
+fn write_bytes_zeroes_buffer() {
+    let locals = [<[u32; 4]>::get_ptype()];
+    let ptr = addr_of(local(0), <*mut u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_array(&[const_int::<u32>(7); 4], <u32>::get_type())),
+        write_bytes(ptr, const_int::<u8>(0), const_int::<usize>(16), 1),
+    );
+    let b1 = block!( print(load(index(local(0), const_int::<usize>(2))), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    assert_stdout(program(&[f]), &["0"]);
+}
+
+/// Only the first `count` bytes are written, and the pointer does not need to be aligned.
+#[test]
+fn write_bytes_unaligned_prefix() {
+    let locals = [<u32>::get_ptype()];
+    let ptr = ptr_offset(addr_of(local(0), <*mut u8>::get_type()), const_int::<usize>(1), InBounds::Yes);
+
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_int::<u32>(0)),
+        write_bytes(ptr, const_int::<u8>(1), const_int::<usize>(2), 1),
+    );
+    let b1 = block!( print(load(local(0)), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    assert_stdout(program(&[f]), &["65792"]);
+}
@@ -0,0 +1,46 @@
+use crate::*;
+
+/// Loads and stores of zero-sized places are no-ops, even through a dangling pointer.
+#[test]
+fn zst_load_store() {
+    let locals = locals!(a: (), b: [i32; 0], p: *mut ());
+    let dangling = deref(load(local(2)), <()>::get_ptype());
+
+    let stmts = &[
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_unit()),
+        assign(local(0), load(local(0))),
+        assign(local(1), const_array(&[], <i32>::get_type())),
+        assign(local(1), load(local(1))),
+        assign(local(2), dangling_ptr(<*mut ()>::get_type(), align(1))),
+        assign(dangling, load(local(0))),
+        assign(local(0), load(dangling)),
+    ];
+
+    let p = small_program(&locals, stmts);
+    dump_program(p);
+    assert_stop(p);
+}
+
+/// Indexing an array of ZSTs, or offsetting by a ZST element size, does not move the pointer.
+#[test]
+fn zst_offset() {
+    let locals = locals!(arr: [(); 4], p: *const ());
+    let ptr_ty = <*const ()>::get_type();
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), ptr_ty)),
+        print(ptr_eq(addr_of_index(local(0), const_int::<usize>(3), ptr_ty), load(local(1))), 1)
+    );
+    let b1 = block!(
+        print(ptr_eq(offset_inbounds(load(local(1)), const_int::<usize>(0)), load(local(1))), 2)
+    );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    assert_stdout(program(&[f]), &["true", "true"]);
+}
@@ -0,0 +1,12 @@
+use crate::*;
+
+#[test]
+fn abs_overflow() {
+    let locals = [<i8>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), abs::<i8>(const_int::<i8>(i8::MIN))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "overflow in absolute value");
+}
@@ -0,0 +1,53 @@
+use crate::*;
+
+/// Creates two mutable references to local(0) in local(1) and local(2),
+/// and then writes through the first one.
+fn stale_mut_ref() -> Program {
+    let locals = [<u32>::get_ptype(), <&mut u32>::get_ptype(), <&mut u32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int::<u32>(0)),
+        assign(local(1), addr_of(local(0), <&mut u32>::get_type())),
+        finalize(local(1), false),
+        assign(local(2), addr_of(local(0), <&mut u32>::get_type())),
+        finalize(local(2), false),
+        assign(deref(load(local(1)), <u32>::get_ptype()), const_int::<u32>(1)),
+    ];
+    small_program(&locals, &stmts)
+}
+
+#[test]
+fn write_through_stale_mut_ref() {
+    let p = stale_mut_ref();
+    assert_aliasing_ub(p, "aliasing violation: access through a pointer whose borrow was invalidated");
+}
+
+/// Aliasing checks are disabled by default.
+#[test]
+fn stale_mut_ref_unchecked() {
+    assert_stop(stale_mut_ref());
+}
+
+/// A reborrow of a mutable reference does not invalidate it, but using it invalidates the reborrow.
+#[test]
+fn reborrow_mut_ref() {
+    let locals = [<u32>::get_ptype(), <&mut u32>::get_ptype(), <&mut u32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_int::<u32>(0)),
+        assign(local(1), addr_of(local(0), <&mut u32>::get_type())),
+        finalize(local(1), false),
+        assign(local(2), addr_of(deref(load(local(1)), <u32>::get_ptype()), <&mut u32>::get_type())),
+        finalize(local(2), false),
+        assign(deref(load(local(2)), <u32>::get_ptype()), const_int::<u32>(1)),
+        assign(deref(load(local(1)), <u32>::get_ptype()), const_int::<u32>(2)),
+    ];
+    assert_eq!(run_program_with_aliasing_checks(small_program(&locals, &stmts)), TerminationInfo::MachineStop { code: Int::ZERO });
+
+    let stmts = [&stmts[..], &[assign(deref(load(local(2)), <u32>::get_ptype()), const_int::<u32>(3))]].concat();
+    assert_aliasing_ub(small_program(&locals, &stmts), "aliasing violation: access through a pointer whose borrow was invalidated");
+}
@@ -147,3 +147,33 @@ fn alloc_wrongreturn() {
     dump_program(p);
     assert_ub(p, "invalid return type for `Intrinsic::Allocate`");
 }
+
+#[test]
+fn alloc_zero_size() {
+    let locals = [ <*const i32>::get_ptype() ];
+
+    let b0 = block!(
+        storage_live(0),
+        allocate(const_int::<usize>(0), const_int::<usize>(4), local(0), 1),
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid size for `Intrinsic::Allocate`: zero size");
+}
+
+#[test]
+fn alloc_align_not_power_of_two() {
+    let locals = [ <*const i32>::get_ptype() ];
+
+    let b0 = block!(
+        storage_live(0),
+        allocate(const_int::<usize>(4), const_int::<usize>(6), local(0), 1),
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid alignment for `Intrinsic::Allocate`: not a power of 2");
+}
@@ -0,0 +1,61 @@
+use crate::*;
+
+fn alloc_size_of(ptr: impl Fn(ValueExpr) -> ValueExpr, dealloc_first: bool) -> Program {
+    let locals = [<*const u8>::get_ptype(), <usize>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        allocate(const_int::<usize>(16), const_int::<usize>(8), local(0), 1),
+    );
+    let b1 = if dealloc_first {
+        block!( deallocate(load(local(0)), const_int::<usize>(16), const_int::<usize>(8), 2) )
+    } else {
+        block!( goto(2) )
+    };
+    let b2 = block!( alloc_size(ptr(load(local(0))), local(1), 3) );
+    let b3 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    program(&[f])
+}
+
+#[test]
+fn alloc_size_not_base() {
+    let p = alloc_size_of(|p| ptr_offset(p, const_int::<usize>(1), InBounds::Yes), false);
+    assert_ub(p, "querying the size with pointer not to the beginning of its allocation");
+}
+
+#[test]
+fn alloc_size_after_free() {
+    let p = alloc_size_of(|p| p, true);
+    assert_ub(p, "querying the size of a deallocated allocation");
+}
+
+#[test]
+fn alloc_size_of_local() {
+    let locals = [<u32>::get_ptype(), <usize>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        alloc_size(addr_of(local(0), <*const u32>::get_type()), local(1), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "querying the size of Stack memory");
+}
+
+#[test]
+fn alloc_size_of_global() {
+    let locals = [<usize>::get_ptype()];
+    let b0 = block!(
+        storage_live(0),
+        alloc_size(addr_of(global::<u32>(0), <*const u32>::get_type()), local(0), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program_with_globals(&[f], &[global_int::<u32>()]);
+    assert_ub(p, "querying the size of Global memory");
+}
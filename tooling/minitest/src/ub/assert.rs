@@ -0,0 +1,11 @@
+use crate::*;
+
+#[test]
+fn assert_message_invalid_utf8() {
+    let b0 = block!( assert(const_bool(false), 0, 1) );
+    let b1 = block!( exit() );
+    let main = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let msg = Global { bytes: list![Some(0xff)], relocations: list![], align: Align::ONE, thread_local: false };
+    assert_ub(program_with_globals(&[main], &[msg]), "assertion message that is not valid UTF-8");
+}
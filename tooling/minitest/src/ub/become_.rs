@@ -0,0 +1,60 @@
+use crate::*;
+
+/// Function 1 takes one `u32` and returns it.
+fn id() -> Function {
+    let locals = locals!(ret: u32, x: u32);
+    let b0 = block!( assign(local(0), load(local(1))), return_() );
+
+    function(Ret::Yes, 1, &locals, &[b0])
+}
+
+// The same mismatch as in `ill_formed::become_`, but through a function pointer
+// that is only known at runtime.
+#[test]
+fn dynamic_become_arg_count() {
+    let locals = locals!(ret: u32);
+    let b0 = block!( storage_live(0), call(2, &[], Some(local(0)), Some(1)) );
+    let b1 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    // Function 2 returns like function 1, but passes it no argument.
+    let locals = [<u32>::get_ptype(), ptype(fn_ptr_ty(), <usize>::get_align())];
+    let b0 = block!(
+        storage_live(1),
+        assign(local(1), fn_ptr(1)),
+        become_call(load(local(1)), &[])
+    );
+    let f = function(Ret::Yes, 0, &locals, &[b0]);
+
+    let p = program(&[main, id(), f]);
+    assert_ub(p, "tail call ABI violation: number of arguments does not agree");
+}
+
+#[test]
+fn become_ret_abi() {
+    let locals = locals!(ret: u32);
+    let b0 = block!( storage_live(0), call(2, &[], Some(local(0)), Some(1)) );
+    let b1 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    // Function 1 returns its value on the stack, but function 2 returns in a register.
+    let mut on_stack = id();
+    on_stack.ret = Some((LocalName(Name::from_internal(0)), ArgAbi::Stack(size(4), align(4))));
+
+    let locals = locals!(ret: u32);
+    let b0 = block!( become_call(fn_ptr(1), &[const_int::<u32>(1)]) );
+    let f = function(Ret::Yes, 0, &locals, &[b0]);
+
+    let p = program(&[main, on_stack, f]);
+    assert_ub(p, "tail call ABI violation: return ABI does not agree");
+}
+
+/// The main function has no return local, but the callee has one.
+#[test]
+fn become_ret_missing() {
+    let b0 = block!( become_call(fn_ptr(1), &[const_int::<u32>(1)]) );
+    let main = function(Ret::No, 0, &[], &[b0]);
+
+    let p = program(&[main, id()]);
+    assert_ub(p, "tail call ABI violation: return ABI does not agree");
+}
@@ -0,0 +1,15 @@
+use crate::*;
+
+#[test]
+fn black_box_wrong_return_type() {
+    let locals = locals!(x: u64);
+
+    let b0 = block!(
+        storage_live(0),
+        black_box(const_int::<u32>(1), local(0), 1)
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "invalid return type for `Intrinsic::BlackBox`");
+}
@@ -0,0 +1,15 @@
+use crate::*;
+
+#[test]
+fn likely_non_bool() {
+    let locals = locals!(c: bool);
+
+    let b0 = block!(
+        storage_live(0),
+        likely(const_int::<u8>(1), local(0), 1)
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "invalid first argument to `Intrinsic::Likely`");
+}
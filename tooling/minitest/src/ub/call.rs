@@ -22,6 +22,7 @@ fn call_success() {
             arguments: list![(const_unit(), ArgAbi::Register)],
             ret: Some((local(0), ArgAbi::Register)),
             next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
         }
     );
     let b1 = block!(exit());
@@ -43,6 +44,7 @@ fn call_non_exist() {
             arguments: list![(const_unit(), ArgAbi::Register)],
             ret: Some((local(0), ArgAbi::Register)),
             next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
         }
     );
     let b1 = block!(exit());
@@ -50,20 +52,24 @@ fn call_non_exist() {
     let f = function(Ret::No, 0, &locals, &[b0, b1]);
     let p = program(&[f]);
     dump_program(p);
-    assert_ill_formed(p);
+    assert_ill_formed_msg(p, "function pointer refers to non-existing function");
 }
 
 #[test]
 fn call_arg_count() {
-    let locals = [<()>::get_ptype()];
+    // The callee is only known at runtime.
+    let locals = [<()>::get_ptype(), ptype(fn_ptr_ty(), <usize>::get_align())];
 
     let b0 = block!(
         storage_live(0),
+        storage_live(1),
+        assign(local(1), fn_ptr(1)),
         Terminator::Call {
-            callee: fn_ptr(1),
+            callee: load(local(1)),
             arguments: list![],
             ret: Some((local(0), ArgAbi::Register)),
             next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
         }
     );
     let b1 = block!(exit());
@@ -76,15 +82,18 @@ fn call_arg_count() {
 
 #[test]
 fn call_arg_abi() {
-    let locals = [<()>::get_ptype()];
+    let locals = [<()>::get_ptype(), ptype(fn_ptr_ty(), <usize>::get_align())];
 
     let b0 = block!(
         storage_live(0),
+        storage_live(1),
+        assign(local(1), fn_ptr(1)),
         Terminator::Call {
-            callee: fn_ptr(1),
+            callee: load(local(1)),
             arguments: list![(const_unit(), other_arg_abi())],
             ret: Some((local(0), ArgAbi::Register)),
             next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
         }
     );
     let b1 = block!(exit());
@@ -97,15 +106,18 @@ fn call_arg_abi() {
 
 #[test]
 fn call_ret_abi() {
-    let locals = [<()>::get_ptype()];
+    let locals = [<()>::get_ptype(), ptype(fn_ptr_ty(), <usize>::get_align())];
 
     let b0 = block!(
         storage_live(0),
+        storage_live(1),
+        assign(local(1), fn_ptr(1)),
         Terminator::Call {
-            callee: fn_ptr(1),
+            callee: load(local(1)),
             arguments: list![(const_unit(), ArgAbi::Register)],
             ret: Some((local(0), other_arg_abi())),
             next_block: Some(BbName(Name::from_internal(1))),
+            unwind_block: None,
         }
     );
     let b1 = block!(exit());
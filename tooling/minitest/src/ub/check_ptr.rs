@@ -75,5 +75,27 @@ fn use_after_free() {
     );
     let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
     let p = program(&[f]);
-    assert_ub(p, "memory accessed after deallocation");
+    assert_ub_prefix(p, "memory accessed after deallocation of allocation");
+}
+
+#[test]
+fn read_after_free() {
+    let locals = vec![<*const i32>::get_ptype(), <i32>::get_ptype()];
+    let n = const_int::<usize>(4);
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        allocate(n, n, local(0), 1),
+    );
+    let b1 = block!(
+        assign(deref(load(local(0)), <i32>::get_ptype()), const_int::<i32>(42)),
+        deallocate(load(local(0)), n, n, 2),
+    );
+    let b2 = block!(
+        assign(local(1), load(deref(load(local(0)), <i32>::get_ptype()))),
+        exit()
+    );
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = program(&[f]);
+    assert_ub_prefix(p, "memory accessed after deallocation of allocation");
 }
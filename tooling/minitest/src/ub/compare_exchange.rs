@@ -2,7 +2,7 @@ use crate::*;
 
 #[test]
 fn compare_exchange_success() {
-    let locals = [ <u32>::get_ptype(); 2 ];
+    let locals = [ <u32>::get_ptype(), <(u32, bool)>::get_ptype() ];
 
     let ptr_ty = raw_ptr_ty( <u32>::get_layout() );
 
@@ -21,24 +21,30 @@ fn compare_exchange_success() {
     );
     let b2 = block!(
         // print CAS return value
-        print(load(local(1)), 3)
+        print(load(field(local(1), 0)), 3)
     );
-
-    // Failure case: check that we do not perform a store
     let b3 = block!(
-        compare_exchange(local(1), addr0, const_int::<u32>(3), const_int::<u32>(42), 4)
+        print(load(field(local(1), 1)), 4)
     );
+
+    // Failure case: check that we do not perform a store
     let b4 = block!(
-        // print value of CASed location
-        print(load(local(0)), 5)
+        compare_exchange(local(1), addr0, const_int::<u32>(3), const_int::<u32>(42), 5)
     );
     let b5 = block!(
+        // print value of CASed location
+        print(load(local(0)), 6)
+    );
+    let b6 = block!(
         // print CAS return value
-        print(load(local(1)), 6)
+        print(load(field(local(1), 0)), 7)
     );
-    let b6 = block!(exit());
+    let b7 = block!(
+        print(load(field(local(1), 1)), 8)
+    );
+    let b8 = block!(exit());
 
-    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6]);
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4, b5, b6, b7, b8]);
     let p = program(&[f]);
 
     // Check that we exchange in the first case but not the second
@@ -46,12 +52,12 @@ fn compare_exchange_success() {
         Ok(out) => out,
         Err(err) => panic!("{:?}", err),
     };
-    assert_eq!(&out[..4], &["1", "0", "1", "1"]);
+    assert_eq!(&out[..6], &["1", "0", "true", "1", "1", "false"]);
 }
 
 #[test]
 fn compare_exchange_arg_count() {
-    let locals = [ <u32>::get_ptype(); 2 ];
+    let locals = [ <u32>::get_ptype(), <(u32, bool)>::get_ptype() ];
 
     let ptr_ty = raw_ptr_ty( <u32>::get_layout() );
     let addr0 = addr_of(local(0), ptr_ty);
@@ -91,7 +97,7 @@ fn compare_exchange_arg_1_value() {
 
 #[test]
 fn compare_exchange_ret_type() {
-    let locals = [ <[u8; 3]>::get_ptype(); 2 ];
+    let locals = [ <[u8; 3]>::get_ptype(), <([u8; 3], bool)>::get_ptype() ];
 
     let ptr_ty = raw_ptr_ty( <[u8; 3]>::get_layout() );
     let addr0 = addr_of(local(0), ptr_ty);
@@ -107,12 +113,12 @@ fn compare_exchange_ret_type() {
 
     let f = function(Ret::No, 0, &locals, &[b0, b1]);
     let p = program(&[f]);
-    assert_ub(p, "invalid return type for `Intrinis::CompareExchange`, only works with integers");
+    assert_ub(p, "invalid return type for `Intrinsic::CompareExchange`, only works with integers");
 }
 
 #[test]
 fn compare_exchange_arg_1_type() {
-    let locals = [ <u32>::get_ptype(); 2 ];
+    let locals = [ <u32>::get_ptype(), <(u32, bool)>::get_ptype() ];
 
     let ptr_ty = raw_ptr_ty( <u32>::get_layout() );
     let addr0 = addr_of(local(0), ptr_ty);
@@ -132,7 +138,7 @@ fn compare_exchange_arg_1_type() {
 
 #[test]
 fn compare_exchange_arg_2_type() {
-    let locals = [ <u32>::get_ptype(); 2 ];
+    let locals = [ <u32>::get_ptype(), <(u32, bool)>::get_ptype() ];
 
     let ptr_ty = raw_ptr_ty( <u32>::get_layout() );
     let addr0 = addr_of(local(0), ptr_ty);
@@ -152,7 +158,7 @@ fn compare_exchange_arg_2_type() {
 
 #[test]
 fn compare_exchange_arg_size_max() {
-    let locals = [ <u128>::get_ptype(); 2 ];
+    let locals = [ <u128>::get_ptype(), <(u128, bool)>::get_ptype() ];
 
     let ptr_ty = raw_ptr_ty( <u128>::get_layout() );
     let addr0 = addr_of(local(0), ptr_ty);
@@ -167,5 +173,25 @@ fn compare_exchange_arg_size_max() {
 
     let f = function(Ret::No, 0, &locals, &[b0, b1]);
     let p = program(&[f]);
-    assert_ub(p, "invalid return type for `Intrinsic::CompareExchange`, size to big");
+    assert_ub(p, "invalid return type for `Intrinsic::CompareExchange`, size too big");
+}
+
+#[test]
+fn compare_exchange_ret_not_tuple() {
+    let locals = [ <u32>::get_ptype(); 2 ];
+
+    let ptr_ty = raw_ptr_ty( <u32>::get_layout() );
+    let addr0 = addr_of(local(0), ptr_ty);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<u32>(0)),
+        compare_exchange(local(1), addr0, const_int::<u32>(0), const_int::<u32>(1), 1)
+    );
+    let b1 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid return type for `Intrinsic::CompareExchange`, must be a `(T, bool)` tuple");
 }
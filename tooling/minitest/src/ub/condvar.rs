@@ -0,0 +1,51 @@
+use crate::*;
+
+#[test]
+fn wait_non_existent() {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        create_lock(local(0), 1),
+    );
+    let b1 = block!( acquire(load(local(0)), 2) );
+    let b2 = block!( condvar_wait(const_int::<u32>(0), load(local(0)), 3) );
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[f]);
+    assert_ub(p, "waiting on non-existing condvar")
+}
+
+#[test]
+fn wait_without_lock() {
+    let locals = [<u32>::get_ptype(); 2];
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        create_lock(local(0), 1),
+    );
+    let b1 = block!( create_condvar(local(1), 2) );
+    let b2 = block!( condvar_wait(load(local(1)), load(local(0)), 3) );
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[f]);
+    assert_ub(p, "waiting on condvar without holding the lock")
+}
+
+#[test]
+fn notify_arg_value() {
+    let locals = [<()>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        notify_all(load(local(0)), 1),
+    );
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    let p = program(&[f]);
+    assert_ub(p, "invalid first argument to `CondvarIntrinsic::NotifyAll`")
+}
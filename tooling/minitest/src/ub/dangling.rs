@@ -0,0 +1,38 @@
+use crate::*;
+
+#[test]
+fn null_ne_real_pointer() {
+    let locals = [<u32>::get_ptype()];
+    let real = addr_of(local(0), <*const u32>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        print(ptr_eq(null_ptr(<*const u32>::get_type()), real), 1),
+    );
+    let b1 = block!( print(is_null(null_ptr(<*const u32>::get_type())), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    assert_stdout(program(&[f]), &["false", "true"]);
+}
+
+#[test]
+fn dangling_zero_sized_read() {
+    let locals = [<()>::get_ptype()];
+    let dangling = dangling_ptr(<*const ()>::get_type(), <u32>::get_align());
+    let stmts = [
+        storage_live(0),
+        assign(local(0), load(deref(dangling, <()>::get_ptype()))),
+    ];
+    assert_stop(small_program(&locals, &stmts));
+}
+
+#[test]
+fn dangling_read() {
+    let locals = [<u32>::get_ptype()];
+    let dangling = dangling_ptr(<*const u32>::get_type(), <u32>::get_align());
+    let stmts = [
+        storage_live(0),
+        assign(local(0), load(deref(dangling, <u32>::get_ptype()))),
+    ];
+    assert_ub(small_program(&locals, &stmts), "non-zero-sized access with invalid pointer");
+}
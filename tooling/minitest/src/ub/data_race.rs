@@ -39,12 +39,18 @@ fn access_block(access: AccessPattern, support_global: u32, next: u32) -> BasicB
 }
 
 fn racy_program(main_access: AccessPattern, s_access: AccessPattern) -> Program {
+    racy_program_spawning(main_access, s_access, 1)
+}
+
+// Like `racy_program`, but the program contains two identical copies of the second thread's
+// function (`f1` and `f2`), and `spawned` picks the one that is run.
+fn racy_program_spawning(main_access: AccessPattern, s_access: AccessPattern, spawned: u32) -> Program {
     // The main thread.
     let main_locals = [<u32>::get_ptype()];
 
     let main_b0 = block!(
         storage_live(0),
-        spawn(fn_ptr(1), Some(local(0)), 1),
+        spawn(fn_ptr(spawned), Some(local(0)), 1),
     );
     let main_b1 = access_block(main_access, 1, 2);
     let main_b2 = block!(
@@ -65,7 +71,7 @@ fn racy_program(main_access: AccessPattern, s_access: AccessPattern) -> Program
     // before the race condition which would decrease the chance of it being caught.
     let globals = [global_int::<u32>(); 3];
 
-    program_with_globals(&[main, s_fun], &globals)
+    program_with_globals(&[main, s_fun, s_fun], &globals)
 }
 
 #[test]
@@ -167,3 +173,65 @@ fn non_atomic_store_non_atomic_store() {
 
     assert!(has_data_race(p))
 }
+
+/// The UB message names both accesses and where they happened.
+#[test]
+fn data_race_message() {
+    let p = racy_program(
+        AccessPattern(AccessType::Store, Atomicity::None),
+        AccessPattern(AccessType::Load, Atomicity::Atomic)
+    );
+
+    let msg = find_data_race(p).unwrap();
+    assert!(msg.contains("non-atomic store of 4 bytes"));
+    assert!(msg.contains("atomic load of 4 bytes"));
+    assert!(msg.contains("by thread 0 at f0, bb1, statement 0"));
+    assert!(msg.contains("by thread 1 at f1, bb0, statement 0"));
+}
+
+/// The message names the function that was spawned, even if another function has the same body.
+#[test]
+fn data_race_message_identical_functions() {
+    let p = racy_program_spawning(
+        AccessPattern(AccessType::Store, Atomicity::None),
+        AccessPattern(AccessType::Store, Atomicity::None),
+        2
+    );
+
+    let msg = find_data_race(p).unwrap();
+    assert!(msg.contains("by thread 1 at f2, bb0, statement 0"));
+}
+
+/// Both threads store to global(0) while holding the lock in global(1).
+#[test]
+fn locked_stores_no_race() {
+    let lock = load(global::<u32>(1));
+    let locals = [<u32>::get_ptype()];
+
+    let main_b0 = block!(
+        storage_live(0),
+        create_lock(global::<u32>(1), 1),
+    );
+    let main_b1 = block!( spawn(fn_ptr(1), Some(local(0)), 2) );
+    let main_b2 = block!( acquire(lock, 3) );
+    let main_b3 = block!(
+        assign(global::<u32>(0), const_int::<u32>(1)),
+        release(lock, 4),
+    );
+    let main_b4 = block!( join(load(local(0)), 5) );
+    let main_b5 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[main_b0, main_b1, main_b2, main_b3, main_b4, main_b5]);
+
+    let s_b0 = block!( acquire(lock, 1) );
+    let s_b1 = block!(
+        assign(global::<u32>(0), const_int::<u32>(2)),
+        release(lock, 2),
+    );
+    let s_b2 = block!( return_() );
+    let s_fun = function(Ret::No, 0, &[], &[s_b0, s_b1, s_b2]);
+
+    let globals = [global_int::<u32>(); 2];
+    let p = program_with_globals(&[main, s_fun], &globals);
+
+    assert!(!has_data_race(p))
+}
@@ -0,0 +1,23 @@
+use crate::*;
+
+#[test]
+fn div_overflow() {
+    let locals = [<i32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), div::<i32>(const_int::<i32>(i32::MIN), const_int::<i32>(-1))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "overflow in signed division");
+}
+
+#[test]
+fn rem_overflow() {
+    let locals = [<i32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), rem::<i32>(const_int::<i32>(i32::MIN), const_int::<i32>(-1))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "overflow in signed remainder");
+}
@@ -21,3 +21,48 @@ fn div_zero() {
     dump_program(p);
     assert_ub(p, "division by zero");
 }
+
+#[test]
+fn div_zero_unsigned() {
+    let locals = [<u32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), div::<u32>(const_int::<u32>(1), const_int::<u32>(0))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "division by zero");
+}
+
+#[test]
+fn rem_zero() {
+    let locals = [<i32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), rem::<i32>(const_int::<i32>(1), const_int::<i32>(0))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "division by zero");
+}
+
+#[test]
+fn rem_zero_unsigned() {
+    let locals = [<u32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), rem::<u32>(const_int::<u32>(1), const_int::<u32>(0))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "division by zero");
+}
+
+/// `MIN / 0` is a division by zero, not an overflow.
+#[test]
+fn div_zero_min() {
+    let locals = [<i32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), div::<i32>(const_int::<i32>(i32::MIN), const_int::<i32>(0))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "division by zero");
+}
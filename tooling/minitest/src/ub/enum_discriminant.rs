@@ -0,0 +1,33 @@
+use crate::*;
+
+/// An enum with two fieldless variants and a `u8` tag.
+fn two_variants_ty() -> Type {
+    enum_ty::<u8>(&[<()>::get_type(), <()>::get_type()], size(0), size(1))
+}
+
+/// Runs `stmts` on local(0) of type `two_variants_ty()`, then reads its discriminant.
+fn read_discriminant(stmts: &[Statement]) -> Program {
+    let locals = [ptype(two_variants_ty(), align(1)), <u8>::get_ptype()];
+
+    let mut all = vec![storage_live(0), storage_live(1)];
+    all.extend_from_slice(stmts);
+    all.push(assign(local(1), discriminant::<u8>(local(0))));
+
+    small_program(&locals, &all)
+}
+
+#[test]
+fn discriminant_uninit_tag() {
+    let p = read_discriminant(&[]);
+    assert_ub(p, "enum value has an invalid tag");
+}
+
+#[test]
+fn discriminant_tag_out_of_range() {
+    // Overwrite the tag with a value that does not denote a variant.
+    let tag_ptr = addr_of(local(0), <*mut u8>::get_type());
+    let p = read_discriminant(&[
+        assign(deref(tag_ptr, <u8>::get_ptype()), const_int::<u8>(2)),
+    ]);
+    assert_ub(p, "enum value has an invalid tag");
+}
@@ -0,0 +1,9 @@
+use crate::*;
+
+#[test]
+fn exit_arg_value() {
+    let b0 = block!(exit_code(const_bool(true)));
+    let f = function(Ret::No, 0, &[], &[b0]);
+    let p = program(&[f]);
+    assert_ub(p, "invalid first argument to `Intrinsic::Exit`");
+}
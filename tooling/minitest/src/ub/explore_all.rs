@@ -0,0 +1,36 @@
+use crate::*;
+
+/// The main thread and a spawned thread both write to global(0), without synchronization.
+fn two_writers() -> Program {
+    let b0 = block!(
+        assign(global::<u32>(0), const_int::<u32>(1)),
+        return_()
+    );
+    let worker = function(Ret::No, 0, &[], &[b0]);
+
+    let locals = locals!(thread: u32);
+    let b0 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 1)
+    );
+    let b1 = block!(
+        assign(global::<u32>(0), const_int::<u32>(2)),
+        join(load(local(0)), 2)
+    );
+    let b2 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let globals = [global_int::<u32>()];
+    program_with_globals(&[main, worker], &globals)
+}
+
+/// The race only shows up if the two writes are executed back to back,
+/// which the exploration is guaranteed to try.
+#[test]
+fn explore_all_finds_race() {
+    let err = explore_all(two_writers(), 100).unwrap_err();
+    assert!(err.msg.starts_with("Data race"), "unexpected UB: {}", err.msg);
+    // Both threads took part in the execution.
+    assert!(err.schedule.contains(&Int::ZERO));
+    assert!(err.schedule.contains(&Int::ONE));
+}
@@ -0,0 +1,33 @@
+use crate::*;
+
+/// Finalizing a reference checks that it is dereferenceable, even if it is never used.
+#[test]
+fn finalize_dangling_ref() {
+    let locals = [<u32>::get_ptype(), <&u32>::get_ptype()];
+
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), <&u32>::get_type())),
+        storage_dead(0),
+        finalize(local(1), false),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub_prefix(p, "memory accessed after deallocation of allocation");
+}
+
+/// Raw pointers are not checked.
+#[test]
+fn finalize_dangling_raw_ptr() {
+    let locals = [<u32>::get_ptype(), <*const u32>::get_ptype()];
+
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), <*const u32>::get_type())),
+        storage_dead(0),
+        finalize(local(1), false),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_stop(p);
+}
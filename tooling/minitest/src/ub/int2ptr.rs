@@ -0,0 +1,62 @@
+use crate::*;
+
+/// Stores `42` in local 0, casts its address to an integer (exposing it unless `expose` is false),
+/// casts that back to a pointer with `int2ptr` and loads through it.
+fn roundtrip(expose: bool, int2ptr: fn(ValueExpr, Type) -> ValueExpr) -> Program {
+    let locals = [<u32>::get_ptype(), <usize>::get_ptype()];
+    let ptr = addr_of(local(0), <*const u32>::get_type());
+    // Transmuting a pointer to an integer does not expose its provenance.
+    let addr = if expose { ptr_to_int(ptr) } else { transmute(ptr, <usize>::get_type()) };
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_int::<u32>(42)),
+        assign(local(1), addr),
+        print(load(deref(int2ptr(load(local(1)), <*const u32>::get_type()), <u32>::get_ptype())), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    program(&[f])
+}
+
+#[test]
+fn exposed_provenance() {
+    let p = roundtrip(true, int_to_ptr);
+    assert!(fmt_program(p).contains("(int2ptr<"));
+    assert_stdout(p, &["42"]);
+}
+
+#[test]
+fn not_exposed() {
+    assert_ub(roundtrip(false, int_to_ptr), "non-zero-sized access with invalid pointer");
+}
+
+#[test]
+fn invalid_provenance() {
+    let p = roundtrip(true, int_to_ptr_invalid);
+    assert!(fmt_program(p).contains("int2ptr_invalid<"));
+    assert_ub(p, "non-zero-sized access with invalid pointer");
+}
+
+/// Exposes the tag of a mutable reference, invalidates it by writing to the local directly,
+/// then exposes the local's own tag. The cast must not pick the invalidated tag.
+#[test]
+fn exposed_provenance_skips_invalidated_tag() {
+    let locals = [<u32>::get_ptype(), <&mut u32>::get_ptype(), <usize>::get_ptype(), <usize>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        storage_live(3),
+        assign(local(0), const_int::<u32>(0)),
+        assign(local(1), addr_of(local(0), <&mut u32>::get_type())),
+        finalize(local(1), false),
+        assign(local(2), ptr_to_int(load(local(1)))),
+        assign(local(0), const_int::<u32>(1)),
+        assign(local(3), ptr_to_int(addr_of(local(0), <*mut u32>::get_type()))),
+        assign(deref(int_to_ptr(load(local(2)), <*mut u32>::get_type()), <u32>::get_ptype()), const_int::<u32>(2)),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_eq!(run_program_with_aliasing_checks(p), TerminationInfo::MachineStop { code: Int::ZERO });
+}
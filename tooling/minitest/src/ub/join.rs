@@ -64,25 +64,45 @@ fn join_arg_value() {
     assert_ub(p, "invalid first argument to `Intrinsic::Join`");
 }
 
-#[test]
-fn join_wrongreturn() {
-    let locals = [ <u32>::get_ptype() ];
+// Function 1 returns nothing, function 2 returns a `u8`.
+fn returning_functions() -> [Function; 2] {
+    let b0 = block!(return_());
+    let no_ret = function(Ret::No, 0, &[], &[b0]);
+
+    let locals = [<u8>::get_ptype()];
+    let b0 = block!(
+        assign(local(0), const_int::<u8>(1)),
+        return_(),
+    );
+    let ret_u8 = function(Ret::Yes, 0, &locals, &[b0]);
+
+    [no_ret, ret_u8]
+}
+
+fn join_into_u32(thread_fn: u32) -> Program {
+    let locals = [ <u32>::get_ptype(), <u32>::get_ptype() ];
 
     let b0 = block!(
         storage_live(0),
-        Terminator::CallIntrinsic {
-            intrinsic: Intrinsic::Join,
-            arguments: list![const_int::<u32>(1)],
-            ret: Some(local(0)),
-            next_block: Some(BbName(Name::from_internal(1))),
-        },
+        storage_live(1),
+        spawn(fn_ptr(thread_fn), Some(local(0)), 1),
     );
-    let b1 = block!(exit());
+    let b1 = block!( join_with_ret(load(local(0)), local(1), 2) );
+    let b2 = block!(exit());
 
-    let f = function(Ret::No, 0, &locals, &[b0, b1]);
-    let p = program(&[f]);
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let [no_ret, ret_u8] = returning_functions();
+    program(&[f, no_ret, ret_u8])
+}
 
-    assert_ub(p, "invalid return type for `Intrinsic::Join`");
+#[test]
+fn join_wrongreturn() {
+    assert_ub(join_into_u32(1), "invalid return type for `Intrinsic::Join`");
+}
+
+#[test]
+fn join_wrongreturn_value() {
+    assert_ub(join_into_u32(2), "invalid return type for `Intrinsic::Join`");
 }
 
 #[test]
@@ -102,3 +122,48 @@ fn join_no_thread() {
 
     assert_ub(p, "`Intrinsic::Join`: join non existing thread");
 }
+
+#[test]
+fn join_twice() {
+    let locals = [ <u32>::get_ptype() ];
+
+    let b0 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 1),
+    );
+    let b1 = block!( join(load(local(0)), 2) );
+    let b2 = block!( join(load(local(0)), 3) );
+    let b3 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+    let [no_ret, _] = returning_functions();
+    let p = program(&[f, no_ret]);
+
+    assert_ub(p, "`Intrinsic::Join`: thread was already joined");
+}
+
+/// Two threads join the same thread. Whichever join completes second is UB.
+#[test]
+fn join_from_two_threads() {
+    // Function 3 joins the thread whose id is in global(0).
+    let b0 = block!( join(load(global::<u32>(0)), 1) );
+    let b1 = block!( return_() );
+    let joiner = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let locals = [ <u32>::get_ptype() ];
+    let b0 = block!( spawn(fn_ptr(1), Some(global::<u32>(0)), 1) );
+    let b1 = block!(
+        storage_live(0),
+        spawn(fn_ptr(3), Some(local(0)), 2),
+    );
+    let b2 = block!( join(load(global::<u32>(0)), 3) );
+    let b3 = block!( join(load(local(0)), 4) );
+    let b4 = block!(exit());
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3, b4]);
+    let [no_ret, ret_u8] = returning_functions();
+    let p = program_with_globals(&[f, no_ret, ret_u8, joiner], &[global_int::<u32>()]);
+
+    let err = explore_all(p, 100).unwrap_err();
+    assert_eq!(err.msg, "`Intrinsic::Join`: thread was already joined");
+}
@@ -0,0 +1,15 @@
+use crate::*;
+
+/// Reading an uninitialized `MaybeUninit<bool>` as `bool` is UB.
+#[test]
+fn read_uninit_as_bool() {
+    let locals = [ptype(maybe_uninit_ty(<bool>::get_type()), align(1)), <bool>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), uninit(<bool>::get_type())),
+        assign(local(1), load(field(local(0), 1))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "load at type PlaceType { ty: Bool, align: Align { raw: Int(Small(1)) } } but the data in memory violates the validity invariant");
+}
@@ -152,3 +152,27 @@ fn double_free() {
     let p = program(&[f]);
     assert_ub(p, "double-free");
 }
+
+#[test]
+fn free_stack_pointer() {
+    let locals = vec![<i32>::get_ptype()];
+    let n = const_int::<usize>(4);
+    let b0 = block!(
+        storage_live(0),
+        deallocate(addr_of(local(0), <*const i32>::get_type()), n, n, 1),
+    );
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    let p = program(&[f]);
+    assert_ub(p, "deallocating Stack memory using Heap deallocation operation");
+}
+
+#[test]
+fn free_global_pointer() {
+    let n = const_int::<usize>(4);
+    let b0 = block!(deallocate(addr_of(global::<i32>(0), <*const i32>::get_type()), n, n, 1));
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+    let p = program_with_globals(&[f], &[global_int_init::<i32>(1)]);
+    assert_ub(p, "deallocating Global memory using Heap deallocation operation");
+}
@@ -23,3 +23,31 @@ mod locks;
 mod atomic;
 mod compare_exchange;
 mod data_race;
+mod rwlocks;
+mod condvar;
+mod exit;
+mod union_uninit;
+mod transmute;
+mod trace;
+mod enum_discriminant;
+mod print_str;
+mod typed_access;
+mod div_overflow;
+mod alloc_size;
+mod reallocate;
+mod abs_overflow;
+mod finalize;
+mod aliasing;
+mod maybe_uninit;
+mod write_bytes;
+mod offset_from;
+mod dangling;
+mod int2ptr;
+mod never;
+mod become_;
+mod explore_all;
+mod zst_index;
+mod branch_hint;
+mod black_box;
+mod thread_local;
+mod assert;
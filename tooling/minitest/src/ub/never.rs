@@ -0,0 +1,42 @@
+use crate::*;
+
+fn never_ptype() -> PlaceType {
+    ptype(never_ty(), align(1))
+}
+
+/// Function 1 returns `!`: it has no return local and exits instead of returning.
+#[test]
+fn never_fn_diverges() {
+    let b0 = block!( call_diverging(1, &[]) );
+    let main = function(Ret::No, 0, &[], &[b0]);
+
+    let b0 = block!( print(const_int::<u32>(1), 1) );
+    let b1 = block!( exit() );
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+
+    assert_stdout(program(&[main, f]), &["1"]);
+}
+
+#[test]
+fn load_never() {
+    let locals = [never_ptype(), never_ptype()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), load(local(0))),
+    ];
+    let TerminationInfo::Ub(msg) = run_program(small_program(&locals, &stmts)) else {
+        panic!("loading a `!` did not cause UB");
+    };
+    assert!(msg.get_internal().contains("violates the validity invariant"));
+}
+
+#[test]
+fn transmute_to_never() {
+    let locals = [never_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), transmute(const_unit(), never_ty())),
+    ];
+    assert_ub(small_program(&locals, &stmts), "transmuted value is not valid at the target type");
+}
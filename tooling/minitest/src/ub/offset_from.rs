@@ -0,0 +1,74 @@
+use crate::*;
+
+/// Local 0 is a `[i32; 4]`, local 1 a pointer to its start and local 2 a pointer
+/// `offset` bytes further. Prints `offset_from(local(2), local(1))` and the reverse.
+fn offset_from_program(offset: usize) -> Program {
+    let locals = [<[i32; 4]>::get_ptype(), <*const i32>::get_ptype(), <*const i32>::get_ptype()];
+    let elems: Vec<_> = (1..=4).map(const_int::<i32>).collect();
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(0), const_array(&elems, <i32>::get_type())),
+        assign(local(1), addr_of(local(0), <*const i32>::get_type())),
+        assign(local(2), offset_inbounds(load(local(1)), const_int::<usize>(offset))),
+        print(offset_from(load(local(2)), load(local(1))), 1),
+    );
+    let b1 = block!( print(offset_from(load(local(1)), load(local(2))), 2) );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f])
+}
+
+#[test]
+fn offset_from_success() {
+    let p = offset_from_program(12);
+    assert!(fmt_program(p).contains("offset_from(load(_2), load(_1))"));
+    assert_stdout(p, &["3", "-3"]);
+}
+
+#[test]
+fn offset_from_one_past_the_end() {
+    assert_stdout(offset_from_program(16), &["4", "-4"]);
+}
+
+#[test]
+fn offset_from_not_a_multiple() {
+    assert_ub(offset_from_program(6), "pointer distance is not a multiple of the pointee size");
+}
+
+#[test]
+fn offset_from_different_allocations() {
+    let locals = [<i32>::get_ptype(), <i32>::get_ptype()];
+    let ptr = |x| addr_of(local(x), <*const i32>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        print(offset_from(ptr(1), ptr(0)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "pointer difference of pointers into different allocations");
+}
+
+/// Only the provenance matters: a pointer one-past-the-end of local 0 that was moved
+/// to the address of local 1 is still in a different allocation than a pointer to local 1.
+#[test]
+fn offset_from_different_allocations_same_address() {
+    let locals = [<i32>::get_ptype(), <i32>::get_ptype(), <*const i32>::get_ptype()];
+    let ptr = |x| addr_of(local(x), <*const i32>::get_type());
+    let addr = |x| ptr_to_int(ptr(x));
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        storage_live(2),
+        assign(local(2), offset_wrapping(ptr(0), sub::<usize>(addr(1), addr(0)))),
+        print(offset_from(load(local(2)), ptr(1)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "pointer difference of pointers into different allocations");
+}
@@ -0,0 +1,79 @@
+use crate::*;
+
+#[test]
+fn print_invalid_utf8() {
+    let locals = [<u8>::get_ptype()];
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_int::<u8>(0xff)),
+        print(str_ref(ptr, const_int::<usize>(1)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "printing a string that is not valid UTF-8");
+}
+
+#[test]
+fn print_uninit_str() {
+    let locals = [<[u8; 2]>::get_ptype()];
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        print(str_ref(ptr, const_int::<usize>(2)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "printing a string with uninitialized bytes");
+}
+
+/// Stores `bytes` in local(0) and prints them as a string.
+fn print_raw_bytes(bytes: &[u8]) -> Program {
+    let n = bytes.len();
+    let locals = [ptype(array_ty(<u8>::get_type(), n), align(1))];
+    let consts: Vec<_> = bytes.iter().copied().map(const_int::<u8>).collect();
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_array(&consts, <u8>::get_type())),
+        print(str_ref(ptr, const_int::<usize>(n)), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    program(&[f])
+}
+
+#[test]
+fn print_overlong_utf8() {
+    assert_ub(print_raw_bytes(&[0xc0, 0x80]), "printing a string that is not valid UTF-8");
+}
+
+#[test]
+fn print_surrogate_utf8() {
+    assert_ub(print_raw_bytes(&[0xed, 0xa0, 0x80]), "printing a string that is not valid UTF-8");
+}
+
+#[test]
+fn print_truncated_utf8() {
+    assert_ub(print_raw_bytes(&[0xe2, 0x82]), "printing a string that is not valid UTF-8");
+}
+
+/// A tuple of a pointer and an integer is only printed as a string if it has the `&str` layout.
+#[test]
+fn print_ptr_int_tuple() {
+    let locals = [<u8>::get_ptype()];
+    let ptr = addr_of(local(0), <*const u8>::get_type());
+    let ty = tuple_ty(&[(size(0), <*const u8>::get_type()), (size(8), <u32>::get_type())], size(16));
+    let b0 = block!(
+        storage_live(0),
+        assign(local(0), const_int::<u8>(b'a')),
+        print(ValueExpr::Tuple(list![ptr, const_int::<u32>(1)], ty), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "unsupported value for printing");
+}
@@ -161,3 +161,20 @@ fn ptr_offset_out_of_bounds() {
     dump_program(p);
     assert_ub(p, "out-of-bounds memory access");
 }
+
+#[test]
+fn offset_inbounds_out_of_allocation() {
+    let locals = [<[i32; 2]>::get_ptype(), <*const i32>::get_ptype()];
+
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), addr_of(local(0), <*const i32>::get_type())),
+        // One past the end is fine ...
+        assign(local(1), offset_inbounds(load(local(1)), const_int::<usize>(8))),
+        // ... but going further is not.
+        assign(local(1), offset_inbounds(load(local(1)), const_int::<usize>(4))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "out-of-bounds memory access");
+}
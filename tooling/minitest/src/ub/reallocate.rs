@@ -0,0 +1,36 @@
+use crate::*;
+
+/// Grows a 4-byte allocation holding 42 to 8 bytes, then runs `stmt`.
+fn grow_then(old_size: u32, stmt: Statement) -> Program {
+    let locals = [<*mut u32>::get_ptype()];
+    let first = deref(load(local(0)), <u32>::get_ptype());
+
+    let b0 = block!(
+        storage_live(0),
+        allocate(const_int::<usize>(4), const_int::<usize>(4), local(0), 1),
+    );
+    let b1 = block!(
+        assign(first, const_int::<u32>(42)),
+        reallocate(load(local(0)), const_int::<usize>(old_size), const_int::<usize>(8), const_int::<usize>(4), local(0), 2),
+    );
+    let b2 = block!(
+        stmt,
+        exit(),
+    );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    program(&[f])
+}
+
+#[test]
+fn reallocate_tail_uninit() {
+    let second = deref(ptr_offset(load(local(0)), const_int::<usize>(4), InBounds::Yes), <u32>::get_ptype());
+    let p = grow_then(4, assign(second, load(second)));
+    assert_ub(p, "load at type PlaceType { ty: Int(IntType { signed: Unsigned, size: Size { raw: Int(Small(4)) } }), align: Align { raw: Int(Small(4)) } } but the data in memory violates the validity invariant");
+}
+
+#[test]
+fn reallocate_wrong_size() {
+    let p = grow_then(2, storage_dead(0));
+    assert_ub(p, "reallocating with incorrect size information");
+}
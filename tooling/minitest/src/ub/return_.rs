@@ -54,11 +54,14 @@ fn return_no_next() {
         function(Ret::Yes, 0, &locals, &[b0])
     };
 
-    let locals = [<()>::get_ptype()];
+    // The callee is only known at runtime, otherwise this would be ill-formed.
+    let locals = [<()>::get_ptype(), ptype(fn_ptr_ty(), <usize>::get_align())];
 
     let b0 = block!(
         storage_live(0),
-        call(1, &[], Some(local(0)), None)
+        storage_live(1),
+        assign(local(1), fn_ptr(1)),
+        call_ptr(load(local(1)), &[], Some(local(0)), None)
     );
 
     let f = function(Ret::No, 0, &locals, &[b0]);
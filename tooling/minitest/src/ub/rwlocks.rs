@@ -0,0 +1,60 @@
+use crate::*;
+
+#[test]
+fn acquire_read_non_existent() {
+    let b0 = block!( acquire_read(const_int::<u32>(0), 1) );
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &[], &[b0, b1]);
+
+    let p = program(&[f]);
+    assert_ub(p, "acquiring non-existing rwlock")
+}
+
+#[test]
+fn acquire_write_arg_value() {
+    let locals = [<()>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        acquire_write(load(local(0)), 1),
+    );
+    let b1 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+
+    let p = program(&[f]);
+    assert_ub(p, "invalid first argument to `RwLockIntrinsic::AcquireWrite`")
+}
+
+#[test]
+fn release_read_not_held() {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        create_rwlock(local(0), 1),
+    );
+    let b1 = block!( acquire_write(load(local(0)), 2) );
+    let b2 = block!( release_read(load(local(0)), 3) );
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[f]);
+    assert_ub(p, "releasing rwlock that is not held for reading")
+}
+
+#[test]
+fn release_write_not_held() {
+    let locals = [<u32>::get_ptype()];
+
+    let b0 = block!(
+        storage_live(0),
+        create_rwlock(local(0), 1),
+    );
+    let b1 = block!( acquire_read(load(local(0)), 2) );
+    let b2 = block!( release_write(load(local(0)), 3) );
+    let b3 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let p = program(&[f]);
+    assert_ub(p, "releasing rwlock that is not held for writing")
+}
@@ -81,40 +81,73 @@ fn spawn_func_takes_args() {
 }
 
 
-fn returns() -> Function {
-    let locals = [<()>::get_ptype()];
-    let b0 = block!(exit());
-    function(Ret::Yes, 0, &locals, &[b0])
+#[test]
+fn spawn_wrongreturn() {
+    let locals = [ <()>::get_ptype() ];
+
+    let b0 = block!(
+        storage_live(0),
+        spawn(fn_ptr(1), Some(local(0)), 1),
+    );
+    let b1 = block!(
+        join(load(local(0)), 2),
+    );
+    let b2 = block!(exit());
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+
+    let p = program(&[f, dummy_function()]);
+    assert_ub(p, "invalid return type for `Intrinsic::Spawn`");
 }
 
 #[test]
-fn spawn_func_returns() {
-    let locals = [<()>::get_ptype()];
+fn spawn_arg_func_takes_no_args() {
+    let locals = [ <u32>::get_ptype() ];
 
     let b0 = block!(
-        spawn(fn_ptr(1), None, 1),
+        storage_live(0),
+        spawn_with_arg(fn_ptr(1), const_int::<u32>(1), local(0), 1),
     );
     let b1 = block!(exit());
     let f = function(Ret::No, 0, &locals, &[b0, b1]);
 
-    let p = program(&[f, returns()]);
-    assert_ub(p, "invalid first argument to `Intrinsic::Spawn`, function returns something")
+    let p = program(&[f, dummy_function()]);
+    assert_ub(p, "invalid first argument to `Intrinsic::Spawn`, function does not take one argument")
+}
+
+/// Takes a `u32` argument.
+fn takes_u32() -> Function {
+    let locals = [<u32>::get_ptype()];
+    let b0 = block!( return_() );
+    function(Ret::No, 1, &locals, &[b0])
 }
 
-#[test]
-fn spawn_wrongreturn() {
-    let locals = [ <()>::get_ptype() ];
+fn spawn_with_arg_program(arg: ValueExpr) -> Program {
+    let locals = [<u32>::get_ptype()];
 
     let b0 = block!(
         storage_live(0),
-        spawn(fn_ptr(1), Some(local(0)), 1),
-    );
-    let b1 = block!(
-        join(load(local(0)), 2),
+        spawn_with_arg(fn_ptr(1), arg, local(0), 1),
     );
+    let b1 = block!( join(load(local(0)), 2) );
     let b2 = block!(exit());
     let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
 
-    let p = program(&[f, dummy_function()]);
-    assert_ub(p, "invalid return type for `Intrinsic::Spawn`");
+    program(&[f, takes_u32()])
+}
+
+#[test]
+fn spawn_arg_too_large() {
+    let p = spawn_with_arg_program(const_int::<u64>(1));
+    assert_ub(p, "spawn ABI violation: argument ABI does not agree");
+}
+
+#[test]
+fn spawn_arg_too_small() {
+    let p = spawn_with_arg_program(const_int::<u8>(1));
+    assert_ub(p, "spawn ABI violation: argument ABI does not agree");
+}
+
+#[test]
+fn spawn_arg_same_size() {
+    assert_stop(spawn_with_arg_program(const_int::<i32>(-1)));
 }
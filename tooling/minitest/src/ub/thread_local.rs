@@ -0,0 +1,23 @@
+use crate::*;
+
+/// A thread stores the address of its instance of the thread-local global(0) in global(1).
+/// That instance is deallocated when the thread terminates, so main cannot read it after the join.
+#[test]
+fn thread_local_after_join() {
+    let b0 = block!(
+        assign(global::<*const u32>(1), addr_of(global::<u32>(0), <*const u32>::get_type())),
+        return_(),
+    );
+    let leak = function(Ret::No, 0, &[], &[b0]);
+
+    let locals = locals!(thread: u32);
+    let b0 = block!( storage_live(0), spawn(fn_ptr(1), Some(local(0)), 1) );
+    let b1 = block!( join(load(local(0)), 2) );
+    let b2 = block!( print(load(deref(load(global::<*const u32>(1)), <u32>::get_ptype())), 3) );
+    let b3 = block!( exit() );
+    let main = function(Ret::No, 0, &locals, &[b0, b1, b2, b3]);
+
+    let globals = [thread_local_int::<u32>(), global_int::<usize>()];
+    let p = program_with_globals(&[main, leak], &globals);
+    assert_ub_prefix(p, "memory accessed after deallocation of allocation");
+}
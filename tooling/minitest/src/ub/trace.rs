@@ -0,0 +1,55 @@
+use crate::*;
+
+/// A use-after-free reports the faulting access and the contents of the dead allocation.
+#[test]
+fn trace_use_after_free() {
+    let locals = [ <*const i32>::get_ptype(), <i32>::get_ptype() ];
+    let n = const_int::<usize>(4);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        allocate(n, n, local(0), 1)
+    );
+    let b1 = block!(
+        assign(
+            deref(load(local(0)), <i32>::get_ptype()),
+            const_int::<i32>(42),
+        ),
+        deallocate(load(local(0)), n, n, 2)
+    );
+    let b2 = block!(
+        assign(
+            local(1),
+            load(deref(load(local(0)), <i32>::get_ptype())),
+        ),
+        exit()
+    );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1, b2]);
+    let p = program(&[f]);
+
+    let Err(trace) = run_program_trace(p) else {
+        panic!("expected UB");
+    };
+
+    assert!(trace.msg.starts_with("memory accessed after deallocation of allocation"));
+    assert_eq!(trace.thread, Int::ZERO);
+    assert_eq!(trace.block, BbName(Name::from_internal(2)));
+    assert_eq!(trace.stmt, Int::ZERO);
+
+    let (addr, len) = trace.access.unwrap();
+    assert_eq!(len, size(4));
+
+    let (start, bytes) = trace.allocation.unwrap();
+    assert_eq!(start, addr);
+    assert_eq!(bytes, list![
+        AbstractByte::Init(42, None),
+        AbstractByte::Init(0, None),
+        AbstractByte::Init(0, None),
+        AbstractByte::Init(0, None)
+    ]);
+
+    let dump = trace.to_string();
+    assert!(dump.contains("2a 00 00 00"));
+}
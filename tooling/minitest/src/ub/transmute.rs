@@ -0,0 +1,12 @@
+use crate::*;
+
+#[test]
+fn transmute_invalid_bool() {
+    let locals = [<bool>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), transmute(const_int::<u8>(2), <bool>::get_type())),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "transmuted value is not valid at the target type");
+}
@@ -0,0 +1,34 @@
+use crate::*;
+
+/// Reading a `u32` at offset 1 of a `u32`-aligned buffer is misaligned.
+#[test]
+fn typed_read_misaligned() {
+    let locals = [<[u32; 2]>::get_ptype(), <u32>::get_ptype()];
+    let ptr = ptr_offset(addr_of(local(0), <*const u8>::get_type()), const_int::<usize>(1), InBounds::Yes);
+
+    let b0 = block!(
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), const_array(&[const_int::<u32>(0); 2], <u32>::get_type())),
+        typed_read(local(1), ptr, <u32>::get_ptype(), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "pointer is insufficiently aligned");
+}
+
+#[test]
+fn typed_write_wrong_type() {
+    let locals = [<u32>::get_ptype()];
+    let ptr = addr_of(local(0), <*mut u32>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        typed_write(ptr, const_int::<u16>(1), <u32>::get_ptype(), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "invalid second argument to `Intrinsic::TypedWrite`, not the given type");
+}
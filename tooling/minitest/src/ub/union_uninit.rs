@@ -0,0 +1,19 @@
+use crate::*;
+
+/// Writing the `u8` field of a union leaves the remaining bytes uninitialized,
+/// so reading the overlapping `u32` field is UB.
+#[test]
+fn union_uninit_read() {
+    let fields = [(size(0), <u8>::get_type()), (size(0), <u32>::get_type())];
+    let union_ty = union_ty(&fields, size(4));
+
+    let locals = [union_ptype(&fields, size(4), align(4)), <u32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        storage_live(1),
+        assign(local(0), union(0, const_int::<u8>(42), union_ty)),
+        assign(local(1), load(field(local(0), 1))),
+    ];
+    let p = small_program(&locals, &stmts);
+    assert_ub(p, "load at type PlaceType { ty: Int(IntType { signed: Unsigned, size: Size { raw: Int(Small(4)) } }), align: Align { raw: Int(Small(4)) } } but the data in memory violates the validity invariant");
+}
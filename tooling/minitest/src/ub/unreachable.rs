@@ -11,3 +11,25 @@ fn reach_unreachable() {
     dump_program(p);
     assert_ub(p, "reached unreachable code");
 }
+
+/// Reaching `Unreachable` through a branch is UB as well.
+#[test]
+fn jump_to_unreachable() {
+    let b0 = block!( if_(const_bool(true), 1, 2) );
+    let b1 = block!( unreachable() );
+    let b2 = block!( exit() );
+
+    let f = function(Ret::No, 0, &[], &[b0, b1, b2]);
+    assert_ub(program(&[f]), "reached unreachable code");
+}
+
+/// The statements before `Unreachable` run normally.
+#[test]
+fn unreachable_after_statements() {
+    let locals = [<u32>::get_ptype()];
+    let stmts = [
+        storage_live(0),
+        assign(local(0), const_int::<u32>(1)),
+    ];
+    assert_ub(small_program_term(&locals, &stmts, unreachable()), "reached unreachable code");
+}
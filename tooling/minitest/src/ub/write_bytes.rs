@@ -0,0 +1,62 @@
+use crate::*;
+
+#[test]
+fn write_bytes_out_of_bounds() {
+    let locals = [<u32>::get_ptype()];
+    let ptr = addr_of(local(0), <*mut u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        write_bytes(ptr, const_int::<u8>(0), const_int::<usize>(5), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "out-of-bounds memory access");
+}
+
+/// The bounds are checked before the bytes are created, so this does not run out of memory.
+#[test]
+fn write_bytes_huge_count() {
+    let locals = [<u32>::get_ptype()];
+    let ptr = addr_of(local(0), <*mut u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        write_bytes(ptr, const_int::<u8>(0), const_int::<usize>(1usize << 40), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "out-of-bounds memory access");
+}
+
+#[test]
+fn write_bytes_count_too_large() {
+    let locals = [<u32>::get_ptype()];
+    let ptr = addr_of(local(0), <*mut u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        write_bytes(ptr, const_int::<u8>(0), const_int::<usize>(usize::MAX), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "invalid count for `Intrinsic::WriteBytes`: count too large");
+}
+
+#[test]
+fn write_bytes_count_not_usize() {
+    let locals = [<u32>::get_ptype()];
+    let ptr = addr_of(local(0), <*mut u8>::get_type());
+
+    let b0 = block!(
+        storage_live(0),
+        write_bytes(ptr, const_int::<u8>(0), const_int::<u32>(4), 1),
+    );
+    let b1 = block!( exit() );
+
+    let f = function(Ret::No, 0, &locals, &[b0, b1]);
+    assert_ub(program(&[f]), "invalid third argument to `Intrinsic::WriteBytes`, not a `usize`");
+}
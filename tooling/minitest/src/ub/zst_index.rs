@@ -0,0 +1,17 @@
+use crate::*;
+
+/// A zero-length array has no elements, so even index 0 is out of bounds.
+#[test]
+fn zero_length_array_index() {
+    let locals = locals!(arr: [i32; 0], x: i32);
+
+    let stmts = &[
+        storage_live(0),
+        storage_live(1),
+        assign(local(1), load(index(local(0), const_int::<usize>(0)))),
+    ];
+
+    let p = small_program(&locals, stmts);
+    dump_program(p);
+    assert_ub(p, "out-of-bounds array access");
+}
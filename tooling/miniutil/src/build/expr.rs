@@ -6,6 +6,13 @@ pub fn const_int<T: TypeConv>(int: impl Into<Int>) -> ValueExpr {
     ValueExpr::Constant(Constant::Int(int.into()), T::get_type())
 }
 
+// A `usize` constant on the target `T`. `const_int::<usize>` always uses `DefaultTarget`.
+// Example usage:
+// `const_usize_on::<Target32>(4)`
+pub fn const_usize_on<T: Target>(int: impl Into<Int>) -> ValueExpr {
+    ValueExpr::Constant(Constant::Int(int.into()), usize_ty::<T>())
+}
+
 pub fn const_bool(b: bool) -> ValueExpr {
     ValueExpr::Constant(Constant::Bool(b), Type::Bool)
 }
@@ -23,11 +30,117 @@ pub fn const_array(args: &[ValueExpr], elem_ty: Type) -> ValueExpr {
     ValueExpr::Tuple(args.iter().cloned().collect(), ty)
 }
 
+// Like `const_array`, but checks that every element has type `elem_ty`,
+// as far as the type of the element expression is known without a function context.
+// Example usage:
+// `array(&[array(&[a, b], <i32>::get_type()), array(&[c, d], <i32>::get_type())], <[i32; 2]>::get_type())`
+pub fn array(elems: &[ValueExpr], elem_ty: Type) -> ValueExpr {
+    for (i, elem) in elems.iter().enumerate() {
+        if let Some(ty) = value_ty(*elem) {
+            assert_eq!(ty, elem_ty, "array element {i} has the wrong type");
+        }
+    }
+    const_array(elems, elem_ty)
+}
+
+// Like `const_tuple`, but also takes the offset of each field and checks that
+// offsets and field types match `tuple_ty`.
+// Example usage:
+// `tuple(&[(const_int::<u8>(1), size(0)), (const_int::<u32>(2), size(4))], <(u8, u32)>::get_type())`
+pub fn tuple(fields: &[(ValueExpr, Size)], tuple_ty: Type) -> ValueExpr {
+    let Type::Tuple { fields: ty_fields, .. } = tuple_ty else {
+        panic!("tuple received non-tuple type!");
+    };
+    assert_eq!(ty_fields.len(), fields.len(), "tuple has the wrong number of fields");
+    for (i, ((expr, offset), (ty_offset, ty))) in fields.iter().zip(ty_fields.iter()).enumerate() {
+        assert_eq!(*offset, ty_offset, "tuple field {i} has the wrong offset");
+        if let Some(expr_ty) = value_ty(*expr) {
+            assert_eq!(expr_ty, ty, "tuple field {i} has the wrong type");
+        }
+    }
+    ValueExpr::Tuple(fields.iter().map(|(expr, _)| *expr).collect(), tuple_ty)
+}
+
+// The type of a value expression that carries its own type, i.e. does not depend on locals.
+fn value_ty(v: ValueExpr) -> Option<Type> {
+    match v {
+        ValueExpr::Constant(_, ty) | ValueExpr::Tuple(_, ty) => Some(ty),
+        ValueExpr::Union { union_ty, .. } => Some(union_ty),
+        ValueExpr::Variant { enum_ty, .. } => Some(enum_ty),
+        _ => None,
+    }
+}
+
+// A string as understood by `print` and `eprint`: a pointer to the bytes and their number, like a `&str`.
+// Example usage:
+// `print(str_ref(addr_of(local(0), <*const u8>::get_type()), const_int::<usize>(5)), 1)`
+pub fn str_ref(ptr: ValueExpr, len: ValueExpr) -> ValueExpr {
+    str_ref_on::<DefaultTarget>(ptr, len)
+}
+
+// Like `str_ref`, but laid out for the target `T`; `len` must be a `usize` of `T`.
+pub fn str_ref_on<T: Target>(ptr: ValueExpr, len: ValueExpr) -> ValueExpr {
+    let fields = [(Size::ZERO, <*const u8>::get_type()), (T::PTR_SIZE, usize_ty::<T>())];
+    let ty = tuple_ty(&fields, T::PTR_SIZE * Int::from(2));
+    ValueExpr::Tuple(list![ptr, len], ty)
+}
+
 // Returns () or [].
 pub fn const_unit() -> ValueExpr {
     ValueExpr::Tuple(Default::default(), <()>::get_type())
 }
 
+// A union value with only `field` initialized. `value` must have the type of that field.
+// Any other field can then be read back from a place of type `union_ty`; it sees the raw bytes.
+// Example usage:
+// `union(0, const_int::<u32>(42), union_ty(&[(size(0), <u32>::get_type()), (size(0), <[u8; 4]>::get_type())], size(4)))`
+pub fn union(field: impl Into<Int>, value: ValueExpr, union_ty: Type) -> ValueExpr {
+    let Type::Union { fields, .. } = union_ty else {
+        panic!("union received non-union type!");
+    };
+    let field = field.into();
+    let Some((_, field_ty)) = fields.get(field) else {
+        panic!("union field {field} does not exist!");
+    };
+    if let Some(value_ty) = value_ty(value) {
+        assert_eq!(value_ty, field_ty, "union field {field} has the wrong type");
+    }
+    ValueExpr::Union {
+        field,
+        expr: GcCow::new(value),
+        union_ty,
+    }
+}
+
+// An uninitialized value of type `maybe_uninit_ty(ty)`, like `MaybeUninit::uninit()`.
+// Reading field 1 of it at type `ty` is UB if `ty` does not allow uninitialized bytes.
+pub fn uninit(ty: Type) -> ValueExpr {
+    union(0, const_unit(), maybe_uninit_ty(ty))
+}
+
+pub fn variant(discriminant: impl Into<Int>, payload: ValueExpr, enum_ty: Type) -> ValueExpr {
+    let Type::Enum { .. } = enum_ty else {
+        panic!("variant received non-enum type!");
+    };
+    ValueExpr::Variant {
+        idx: discriminant.into(),
+        data: GcCow::new(payload),
+        enum_ty,
+    }
+}
+
+// Example usage:
+// `discriminant::<u32>(local(0))`
+pub fn discriminant<T: TypeConv>(place: PlaceExpr) -> ValueExpr {
+    let Type::Int(discriminant_ty) = T::get_type() else {
+        panic!("discriminant requires an integer type!");
+    };
+    ValueExpr::GetDiscriminant {
+        place: GcCow::new(place),
+        discriminant_ty,
+    }
+}
+
 // Non-destructive load.
 pub fn load(p: PlaceExpr) -> ValueExpr {
     ValueExpr::Load {
@@ -65,6 +178,18 @@ pub fn neg<T: TypeConv>(v: ValueExpr) -> ValueExpr {
     }
 }
 
+// Example usage:
+// `abs::<i32>(const_int::<i32>(-42))`
+pub fn abs<T: TypeConv>(v: ValueExpr) -> ValueExpr {
+    let Type::Int(t) = T::get_type() else {
+        panic!("int operator received non-int type!");
+    };
+    ValueExpr::UnOp {
+        operator: UnOp::Int(UnOpInt::Abs, t),
+        operand: GcCow::new(v),
+    }
+}
+
 pub fn int_cast<T: TypeConv>(v: ValueExpr) -> ValueExpr {
     let Type::Int(t) = T::get_type() else {
         panic!("int operator received non-int type!");
@@ -75,6 +200,18 @@ pub fn int_cast<T: TypeConv>(v: ValueExpr) -> ValueExpr {
     }
 }
 
+// Example usage:
+// `bool_to_int::<u8>(const_bool(true))`
+pub fn bool_to_int<T: TypeConv>(v: ValueExpr) -> ValueExpr {
+    let Type::Int(t) = T::get_type() else {
+        panic!("bool_to_int requires an int type!");
+    };
+    ValueExpr::UnOp {
+        operator: UnOp::Bool2Int(t),
+        operand: GcCow::new(v),
+    }
+}
+
 pub fn ptr_to_int(v: ValueExpr) -> ValueExpr {
     ValueExpr::UnOp {
         operator: UnOp::Ptr2Int,
@@ -82,12 +219,33 @@ pub fn ptr_to_int(v: ValueExpr) -> ValueExpr {
     }
 }
 
-pub fn int_to_ptr(v: ValueExpr, t: Type) -> ValueExpr {
+fn int2ptr(v: ValueExpr, t: Type, provenance: Int2PtrProvenance) -> ValueExpr {
     let Type::Ptr(ptr_ty) = t else {
         panic!("int_to_ptr requires Type::Ptr argument!");
     };
     ValueExpr::UnOp {
-        operator: UnOp::Int2Ptr(ptr_ty),
+        operator: UnOp::Int2Ptr(ptr_ty, provenance),
+        operand: GcCow::new(v),
+    }
+}
+
+// Casts an integer to a pointer with some previously exposed provenance, like `ptr::with_exposed_provenance`.
+pub fn int_to_ptr(v: ValueExpr, t: Type) -> ValueExpr {
+    int2ptr(v, t, Int2PtrProvenance::Exposed)
+}
+
+// Casts an integer to a pointer without provenance, like `ptr::without_provenance`.
+// Example usage:
+// `int_to_ptr_invalid(const_int::<usize>(8), <*const u8>::get_type())`
+pub fn int_to_ptr_invalid(v: ValueExpr, t: Type) -> ValueExpr {
+    int2ptr(v, t, Int2PtrProvenance::Invalid)
+}
+
+// Example usage:
+// `transmute(const_int::<u32>(42), <[u8; 4]>::get_type())`
+pub fn transmute(v: ValueExpr, t: Type) -> ValueExpr {
+    ValueExpr::UnOp {
+        operator: UnOp::Transmute(t),
         operand: GcCow::new(v),
     }
 }
@@ -115,6 +273,18 @@ pub fn mul<T: TypeConv>(l: ValueExpr, r: ValueExpr) -> ValueExpr {
 pub fn div<T: TypeConv>(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_binop::<T>(BinOpInt::Div, l, r)
 }
+pub fn rem<T: TypeConv>(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    int_binop::<T>(BinOpInt::Rem, l, r)
+}
+// The ordering depends on the signedness of `T`.
+// Example usage:
+// `imax::<i8>(const_int::<i8>(-1), const_int::<i8>(1))`
+pub fn imin<T: TypeConv>(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    int_binop::<T>(BinOpInt::Min, l, r)
+}
+pub fn imax<T: TypeConv>(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    int_binop::<T>(BinOpInt::Max, l, r)
+}
 
 fn int_rel(op: IntRel, l: ValueExpr, r: ValueExpr) -> ValueExpr {
     ValueExpr::BinOp {
@@ -148,6 +318,54 @@ pub fn lt(l: ValueExpr, r: ValueExpr) -> ValueExpr {
     int_rel(IntRel::Lt, l, r)
 }
 
+fn ptr_rel(op: PtrRel, l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    ValueExpr::BinOp {
+        operator: BinOp::PtrRel(op),
+        left: GcCow::new(l),
+        right: GcCow::new(r),
+    }
+}
+
+// Compares the addresses of two pointers, ignoring provenance.
+pub fn ptr_eq(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    ptr_rel(PtrRel::Eq, l, r)
+}
+
+pub fn ptr_ne(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    ptr_rel(PtrRel::Ne, l, r)
+}
+
+// Example usage:
+// `is_null(load(local(0)))`
+pub fn is_null(ptr: ValueExpr) -> ValueExpr {
+    is_null_on::<DefaultTarget>(ptr)
+}
+
+pub fn is_null_on<T: Target>(ptr: ValueExpr) -> ValueExpr {
+    ptr_eq(ptr, null_ptr_on::<T>(<*const u8>::get_type()))
+}
+
+// A null pointer of type `ptr_ty`, like `ptr::null()`. It has no provenance.
+pub fn null_ptr(ptr_ty: Type) -> ValueExpr {
+    null_ptr_on::<DefaultTarget>(ptr_ty)
+}
+
+pub fn null_ptr_on<T: Target>(ptr_ty: Type) -> ValueExpr {
+    transmute(const_usize_on::<T>(0), ptr_ty)
+}
+
+// A non-null pointer of type `ptr_ty` with address `align`, like `NonNull::dangling()`.
+// It has no provenance, so it can only be used for zero-sized accesses.
+// Example usage:
+// `dangling_ptr(<*const u32>::get_type(), <u32>::get_align())`
+pub fn dangling_ptr(ptr_ty: Type, align: Align) -> ValueExpr {
+    dangling_ptr_on::<DefaultTarget>(ptr_ty, align)
+}
+
+pub fn dangling_ptr_on<T: Target>(ptr_ty: Type, align: Align) -> ValueExpr {
+    transmute(const_usize_on::<T>(align.bytes()), ptr_ty)
+}
+
 pub enum InBounds {
     Yes,
     No,
@@ -163,27 +381,68 @@ pub fn ptr_offset(l: ValueExpr, r: ValueExpr, inbounds: InBounds) -> ValueExpr {
     }
 }
 
+// Offsets `ptr` by `offset` bytes. It is UB if this leaves the allocation of `ptr`.
+pub fn offset_inbounds(ptr: ValueExpr, offset: ValueExpr) -> ValueExpr {
+    ptr_offset(ptr, offset, InBounds::Yes)
+}
+
+// Offsets `ptr` by `offset` bytes, wrapping around the address space.
+pub fn offset_wrapping(ptr: ValueExpr, offset: ValueExpr) -> ValueExpr {
+    ptr_offset(ptr, offset, InBounds::No)
+}
+
+// The distance from `r` to `l` in units of the pointee size of `l`, as an `isize`.
+// It is UB if the pointers are not in the same allocation.
+// Example usage:
+// `offset_from(load(local(1)), load(local(0)))`
+pub fn offset_from(l: ValueExpr, r: ValueExpr) -> ValueExpr {
+    ValueExpr::BinOp {
+        operator: BinOp::PtrOffsetFrom,
+        left: GcCow::new(l),
+        right: GcCow::new(r),
+    }
+}
+
 pub fn local(x: u32) -> PlaceExpr {
     PlaceExpr::Local(LocalName(Name::from_internal(x)))
 }
 
 pub fn global<T: TypeConv>(x: u32) -> PlaceExpr {
+    global_on::<DefaultTarget>(x, T::get_ptype())
+}
+
+// The global `x` as a place of type `ptype` on the target `T`, e.g. for a pointer with the alignment of `T`.
+// Example usage:
+// `global_on::<Target32>(1, ptype(<*const u8>::get_type(), Target32::PTR_ALIGN))`
+pub fn global_on<T: Target>(x: u32, ptype: PlaceType) -> PlaceExpr {
     let relocation = Relocation {
         name: GlobalName(Name::from_internal(x)),
         offset: Size::ZERO
     };
 
     let ptr_type = Type::Ptr(
-        PtrType::Raw{ pointee: T::get_layout() }
+        PtrType::Raw{ pointee: ptype.layout::<BasicMemory<T>>() }
     );
 
     deref(
         ValueExpr::Constant(Constant::GlobalPointer(relocation), ptr_type),
-        T::get_ptype()
+        ptype
     )
 }
 
+// Dereferences `operand` as a place of type `ptype`.
+// The pointee layout of `operand` does not have to match `ptype`, like `*(ptr as *const T)` in Rust.
+// Panics if `operand` is statically known to not be a data pointer.
 pub fn deref(operand: ValueExpr, ptype: PlaceType) -> PlaceExpr {
+    let known_ty = match operand {
+        ValueExpr::AddrOf { ptr_ty, .. } => Some(Type::Ptr(ptr_ty)),
+        _ => value_ty(operand),
+    };
+    if let Some(ty) = known_ty {
+        if !matches!(ty, Type::Ptr(PtrType::Ref { .. } | PtrType::Box { .. } | PtrType::Raw { .. })) {
+            panic!("deref requires a data pointer!");
+        }
+    }
     PlaceExpr::Deref {
         operand: GcCow::new(operand),
         ptype,
@@ -203,3 +462,17 @@ pub fn index(root: PlaceExpr, index: ValueExpr) -> PlaceExpr {
         index: GcCow::new(index),
     }
 }
+
+// Takes the address of a field of `root` without loading it, like `&raw mut (*ptr).field` in Rust.
+// `ty` is the type of the created pointer, it must be a `Type::Ptr`.
+// Example usage:
+// `addr_of_field(deref(load(local(0)), <(u8, u32)>::get_ptype()), 1, <*mut u32>::get_type())`
+pub fn addr_of_field(root: PlaceExpr, f: impl Into<Int>, ty: Type) -> ValueExpr {
+    addr_of(field(root, f), ty)
+}
+
+// Takes the address of an element of the array `root` without loading it, like `&raw mut (*ptr)[i]` in Rust.
+// `ty` is the type of the created pointer, it must be a `Type::Ptr`.
+pub fn addr_of_index(root: PlaceExpr, i: ValueExpr, ty: Type) -> ValueExpr {
+    addr_of(index(root, i), ty)
+}
@@ -4,7 +4,7 @@ pub fn fn_ptr(fn_name: u32) -> ValueExpr {
     let x = Name::from_internal(fn_name as _);
     let x = FnName(x);
     let x = Constant::FnPointer(x);
-    let x = ValueExpr::Constant(x, Type::Ptr(PtrType::FnPtr));
+    let x = ValueExpr::Constant(x, fn_ptr_ty());
     x
 }
 
@@ -117,3 +117,50 @@ pub macro block {
         block!(@{$($stmts),*, $stmt} $($rest),*)
     },
 }
+
+// Builds a chain of `If` blocks that branches on an integer, until there is a `Switch` terminator.
+// The chain consists of one block per arm, which the caller places at consecutive block indices
+// starting with `first`. The block for arm `i` jumps to `arms[i].1` if `scrutinee == arms[i].0`,
+// and to the next block of the chain otherwise. The last block falls back to `default`.
+//
+// Example usage:
+// `match_int::<u32>(load(local(0)), &[(1, 3), (2, 4)], 5, 1)` returns blocks 1 and 2 of the chain.
+pub fn match_int<T: TypeConv>(scrutinee: ValueExpr, arms: &[(i128, u32)], default: u32, first: u32) -> Vec<BasicBlock> {
+    assert!(!arms.is_empty(), "match_int needs at least one arm");
+
+    arms.iter()
+        .enumerate()
+        .map(|(i, &(value, target))| {
+            let otherwise = if i + 1 == arms.len() { default } else { first + i as u32 + 1 };
+            block!( if_(eq(scrutinee, const_int::<T>(value)), target, otherwise) )
+        })
+        .collect()
+}
+
+// Builds a loop that runs the body as long as `cond` holds, then jumps to `exit`.
+// The caller places the returned blocks at consecutive block indices starting with `first`,
+// which is the loop header that evaluates `cond`. The body blocks follow the header:
+// `body(body_start, header)` must return them for placement at `body_start`, and jump back
+// to `header` at the end of each iteration. Both `cond` and the body can use any local of the function.
+//
+// Example usage:
+// `while_loop(lt(load(local(0)), const_int::<u32>(10)), |_, header| vec![block!(assign(local(0), add::<u32>(load(local(0)), const_int::<u32>(1))), goto(header))], 1, 3)`
+// returns blocks 1 (the header) and 2 (the body), and continues at block 3.
+pub fn while_loop(cond: ValueExpr, body: impl FnOnce(u32, u32) -> Vec<BasicBlock>, first: u32, exit: u32) -> Vec<BasicBlock> {
+    let header = block!( if_(cond, first + 1, exit) );
+    let body = body(first + 1, first);
+    assert!(!body.is_empty(), "while_loop needs at least one body block");
+
+    let mut blocks = vec![header];
+    blocks.extend(body);
+    blocks
+}
+
+// match_int!(<T> scrutinee, first; a => bb_a, b => bb_b, _ => default)
+// is syntactic sugar for
+// match_int::<T>(scrutinee, &[(a, bb_a), (b, bb_b)], default, first)
+pub macro match_int {
+    (<$t:ty> $scrutinee:expr, $first:expr; $($value:expr => $target:expr),+ , _ => $default:expr $(,)?) => {
+        match_int::<$t>($scrutinee, &[$(($value, $target)),+], $default, $first)
+    },
+}
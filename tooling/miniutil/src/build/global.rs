@@ -8,5 +8,71 @@ pub fn global_int<T: TypeConv>() -> Global {
         bytes,
         relocations: list!(),
         align: T::get_align(),
+        thread_local: false,
+    }
+}
+
+/// Thread-local Int initialized to zero.
+/// Every thread accesses its own instance through `global::<T>(x)`.
+pub fn thread_local_int<T: TypeConv>() -> Global {
+    Global {
+        thread_local: true,
+        ..global_int::<T>()
+    }
+}
+
+/// Global Int initialized to `value`, with the endianness of `DefaultTarget`.
+/// Panics if `value` does not fit into `T`.
+pub fn global_int_init<T: TypeConv>(value: impl Into<Int>) -> Global {
+    Global {
+        align: T::get_align(),
+        ..global_int_init_on::<DefaultTarget>(T::get_type(), value)
+    }
+}
+
+/// Global Int of type `ty` initialized to `value`, with the endianness of the target `T`.
+/// The global is aligned to the size of `ty`, so `usize_ty::<T>()` gets the alignment of a pointer.
+/// Panics if `ty` is not an Int type or `value` does not fit into it.
+pub fn global_int_init_on<T: Target>(ty: Type, value: impl Into<Int>) -> Global {
+    let Type::Int(IntType { signed, size }) = ty else {
+        panic!("global_int_init received non-int type!");
+    };
+    let bytes = T::ENDIANNESS.encode(signed, size, value.into())
+        .expect("global_int_init: value out of bounds");
+
+    Global {
+        bytes: bytes.map(Some),
+        relocations: list!(),
+        align: Align::from_bytes(size.bytes()).unwrap(),
+        thread_local: false,
+    }
+}
+
+/// Global holding the bytes of `s`, to be used with `str_ref`.
+pub fn global_str(s: &str) -> Global {
+    Global {
+        bytes: s.bytes().map(Some).collect(),
+        relocations: list!(),
+        align: Align::ONE,
+        thread_local: false,
+    }
+}
+
+/// Global pointer to `offset` bytes into the global with name `target`.
+/// The pointer is stored as a relocation, so the address is only known at runtime.
+pub fn global_pointer(target: u32, offset: Size) -> Global {
+    global_pointer_on::<DefaultTarget>(target, offset)
+}
+
+/// Like `global_pointer`, but with the pointer size of the target `T`.
+pub fn global_pointer_on<T: Target>(target: u32, offset: Size) -> Global {
+    let relocation = Relocation {
+        name: GlobalName(Name::from_internal(target)),
+        offset,
+    };
+
+    Global {
+        relocations: list![(Size::ZERO, relocation)],
+        ..global_int_init_on::<T>(usize_ty::<T>(), 0)
     }
 }
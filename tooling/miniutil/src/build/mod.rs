@@ -77,9 +77,27 @@ pub fn program(fns: &[Function]) -> Program {
     program_with_globals(fns, &[])
 }
 
+// Like `program`, but the function at index `start` in `fns` is the start function.
+// Example usage:
+// `program_with_start(&[helper, main], 1)`
+pub fn program_with_start(fns: &[Function], start: usize) -> Program {
+    assert!(start < fns.len(), "start function {start} does not exist");
+    Program {
+        start: FnName(Name::from_internal(start as _)),
+        ..program(fns)
+    }
+}
+
 // Generates a small program with a single basic block.
 pub fn small_program(locals: &[PlaceType], statements: &[Statement]) -> Program {
-    let b = block(statements, exit());
+    small_program_term(locals, statements, exit())
+}
+
+// Like `small_program`, but ends the block with `terminator` instead of `exit()`.
+// Example usage:
+// `small_program_term(&locals, &stmts, unreachable())`
+pub fn small_program_term(locals: &[PlaceType], statements: &[Statement], terminator: Terminator) -> Program {
+    let b = block(statements, terminator);
     let f = function(Ret::No, 0, locals, &[b]);
 
     program(&[f])
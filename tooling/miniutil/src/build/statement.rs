@@ -7,6 +7,13 @@ pub fn assign(destination: PlaceExpr, source: ValueExpr) -> Statement {
     }
 }
 
+// Load the value in `place`, retag the pointers in it and store it back.
+// This is where references and boxes are checked to be dereferenceable.
+// Values that come from outside the current function have not been checked at their type yet,
+// so they should be finalized: the arguments at function entry (with `fn_entry: true`),
+// and the return value after a call returns into the caller's place (with `fn_entry: false`).
+// Example usage:
+// `finalize(local(1), true)`
 pub fn finalize(place: PlaceExpr, fn_entry: bool) -> Statement {
     Statement::Finalize { place, fn_entry }
 }
@@ -31,19 +38,84 @@ pub fn if_(condition: ValueExpr, then_blk: u32, else_blk: u32) -> Terminator {
     }
 }
 
+// Reaching this terminator is UB.
 pub fn unreachable() -> Terminator {
     Terminator::Unreachable
 }
 
+// A call to function `f`. Since the callee is known statically,
+// well-formedness checks that the arguments and return place match its signature.
 pub fn call(f: u32, args: &[ValueExpr], ret: Option<PlaceExpr>, next: Option<u32>) -> Terminator {
+    call_ptr(fn_ptr(f), args, ret, next)
+}
+
+// A call to function `f` that does not return, so there is no return place and no next block.
+// Well-formedness checks that `f` has no return local.
+// Example usage:
+// `call_diverging(1, &[])`
+pub fn call_diverging(f: u32, args: &[ValueExpr]) -> Terminator {
+    call(f, args, None, None)
+}
+
+// A call through an arbitrary function pointer value.
+// Unless `callee` is a constant, a signature mismatch is only detected as UB at runtime.
+// Example usage:
+// `call_ptr(load(local(0)), &[const_int::<u32>(1)], Some(local(1)), Some(1))`
+pub fn call_ptr(callee: ValueExpr, args: &[ValueExpr], ret: Option<PlaceExpr>, next: Option<u32>) -> Terminator {
+    Terminator::Call {
+        callee,
+        arguments: args.iter().map(|x| (*x, ArgAbi::Register)).collect(),
+        ret: ret.map(|x| (x, ArgAbi::Register)),
+        next_block: next.map(|x| BbName(Name::from_internal(x))),
+        unwind_block: None,
+    }
+}
+
+// A call to function `f` that jumps to the cleanup block `unwind` if `f` unwinds.
+// Example usage:
+// `call_unwind(1, &[], None, Some(1), 2)`
+pub fn call_unwind(f: u32, args: &[ValueExpr], ret: Option<PlaceExpr>, next: Option<u32>, unwind: u32) -> Terminator {
     Terminator::Call {
         callee: fn_ptr(f),
         arguments: args.iter().map(|x| (*x, ArgAbi::Register)).collect(),
         ret: ret.map(|x| (x, ArgAbi::Register)),
         next_block: next.map(|x| BbName(Name::from_internal(x))),
+        unwind_block: Some(BbName(Name::from_internal(unwind))),
     }
 }
 
+// A tail call: replaces the current function by `callee`, which then returns to the caller of the current function.
+// If `callee` is a constant, well-formedness checks the arguments against its signature;
+// otherwise a mismatch is only detected as UB at runtime.
+// Example usage:
+// `become_call(fn_ptr(1), &[load(local(0))])`
+pub fn become_call(callee: ValueExpr, args: &[ValueExpr]) -> Terminator {
+    Terminator::Become {
+        callee,
+        arguments: args.iter().map(|x| (*x, ArgAbi::Register)).collect(),
+    }
+}
+
+// Continue unwinding at the end of a cleanup block.
+pub fn resume() -> Terminator {
+    Terminator::Resume
+}
+
+// Call function `f`, which takes no arguments, and store into `ret` whether it unwound.
+// Example usage:
+// `catch_unwind(1, local(0), 1)`
+pub fn catch_unwind(f: u32, ret: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CatchUnwind {
+        callee: fn_ptr(f),
+        ret,
+        next_block: BbName(Name::from_internal(next)),
+    }
+}
+
+// Print `arg` to stdout, then continue at block `next`.
+// The second argument is the index of the next basic block, not a value to print.
+// Example usage:
+// `print(const_int::<u32>(42), 1)` prints "42" and jumps to block 1.
 pub fn print(arg: ValueExpr, next: u32) -> Terminator {
     Terminator::CallIntrinsic {
         intrinsic: Intrinsic::PrintStdout,
@@ -53,6 +125,7 @@ pub fn print(arg: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+// Like `print`, but prints to stderr.
 pub fn eprint(arg: ValueExpr, next: u32) -> Terminator {
     Terminator::CallIntrinsic {
         intrinsic: Intrinsic::PrintStderr,
@@ -62,6 +135,31 @@ pub fn eprint(arg: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+// Print each of `args` to stdout in order, then continue at block `next`.
+// Every print ends a block, so this returns one block per value, which the caller places
+// at consecutive block indices starting with `first`.
+// Example usage:
+// `print_many(&[const_int::<u32>(1), const_int::<u32>(2)], 1, 3)` returns blocks 1 and 2, and continues at block 3.
+pub fn print_many(args: &[ValueExpr], first: u32, next: u32) -> Vec<BasicBlock> {
+    print_chain(print, args, first, next)
+}
+
+// Like `print_many`, but prints to stderr.
+pub fn eprint_many(args: &[ValueExpr], first: u32, next: u32) -> Vec<BasicBlock> {
+    print_chain(eprint, args, first, next)
+}
+
+fn print_chain(print: fn(ValueExpr, u32) -> Terminator, args: &[ValueExpr], first: u32, next: u32) -> Vec<BasicBlock> {
+    let last = args.len() as u32;
+    args.iter()
+        .zip(1..)
+        .map(|(arg, i)| {
+            let target = if i == last { next } else { first + i };
+            block(&[], print(*arg, target))
+        })
+        .collect()
+}
+
 pub fn allocate(size: ValueExpr, align: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
     Terminator::CallIntrinsic {
         intrinsic: Intrinsic::Allocate,
@@ -80,6 +178,101 @@ pub fn deallocate(ptr: ValueExpr, size: ValueExpr, align: ValueExpr, next: u32)
     }
 }
 
+// Moves the allocation of `ptr` to a new one of `new_size` bytes, keeping the common prefix.
+// Example usage:
+// `reallocate(load(local(0)), const_int::<usize>(4), const_int::<usize>(8), const_int::<usize>(4), local(0), 1)`
+pub fn reallocate(ptr: ValueExpr, old_size: ValueExpr, new_size: ValueExpr, align: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Reallocate,
+        arguments: list![ptr, old_size, new_size, align],
+        ret: Some(ret_place),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+// Stores the size of the allocation that `ptr` points to the start of into `ret_place`, which must be a `usize`.
+// Example usage:
+// `alloc_size(load(local(0)), local(1), 1)`
+pub fn alloc_size(ptr: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::AllocSize,
+        arguments: list![ptr],
+        ret: Some(ret_place),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+// Sets `count` bytes starting at `ptr` to `byte`, which must be a `u8`.
+// Example usage:
+// `write_bytes(load(local(0)), const_int::<u8>(0), const_int::<usize>(16), 1)`
+pub fn write_bytes(ptr: ValueExpr, byte: ValueExpr, count: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::WriteBytes,
+        arguments: list![ptr, byte, count],
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+// Panics with the content of global `msg` printed to stderr if `cond` is `false`.
+// Example usage:
+// `assert(load(local(0)), 0, 1)`
+pub fn assert(cond: ValueExpr, msg: u32, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Assert(GlobalName(Name::from_internal(msg))),
+        arguments: list![cond],
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+// Stores in `ret_place` the number of elements `ptr` has to be advanced by to be aligned to `align`,
+// or `usize::MAX` if that is impossible.
+// Example usage:
+// `align_offset(load(local(0)), const_int::<usize>(8), local(1), 1)`
+pub fn align_offset(ptr: ValueExpr, align: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::AlignOffset,
+        arguments: list![ptr, align],
+        ret: Some(ret_place),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+// Stores `cond` in `ret_place`, with the hint that it is probably `true`.
+// Example usage:
+// `likely(load(local(0)), local(1), 1)`
+pub fn likely(cond: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Likely,
+        arguments: list![cond],
+        ret: Some(ret_place),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+// Like `likely`, but with the hint that `cond` is probably `false`.
+pub fn unlikely(cond: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Unlikely,
+        arguments: list![cond],
+        ret: Some(ret_place),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
+// Stores `arg` in `ret_place`, like `core::hint::black_box`. `ret_place` must have the type of `arg`.
+// Example usage:
+// `black_box(load(local(0)), local(1), 1)`
+pub fn black_box(arg: ValueExpr, ret_place: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::BlackBox,
+        arguments: list![arg],
+        ret: Some(ret_place),
+        next_block: Some(BbName(Name::from_internal(next))),
+    }
+}
+
 pub fn exit() -> Terminator {
     Terminator::CallIntrinsic {
         intrinsic: Intrinsic::Exit,
@@ -89,6 +282,33 @@ pub fn exit() -> Terminator {
     }
 }
 
+pub fn abort() -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Abort,
+        arguments: list![],
+        ret: None,
+        next_block: None,
+    }
+}
+
+pub fn panic() -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Panic,
+        arguments: list![],
+        ret: None,
+        next_block: None,
+    }
+}
+
+pub fn exit_code(code: ValueExpr) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Exit,
+        arguments: list![code],
+        ret: None,
+        next_block: None,
+    }
+}
+
 pub fn return_() -> Terminator {
     Terminator::Return
 }
@@ -111,6 +331,40 @@ pub fn join(thread_id: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+// Spawn a thread that runs `fn_ptr` with the single argument `arg`, like a thread closure
+// with captured data. The id of the new thread is stored in `ret`.
+// Example usage:
+// `spawn_with_arg(fn_ptr(1), const_int::<u32>(10), local(0), 1)`
+pub fn spawn_with_arg(fn_ptr: ValueExpr, arg: ValueExpr, ret: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Spawn,
+        arguments: list!(fn_ptr, arg),
+        ret: Some(ret),
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+// Join the thread `thread_id` and store the return value of its function in `ret`.
+// Example usage:
+// `join_with_ret(load(local(0)), local(1), 2)`
+pub fn join_with_ret(thread_id: ValueExpr, ret: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Join,
+        arguments: list!(thread_id),
+        ret: Some(ret),
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn thread_id(ret: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::ThreadId,
+        arguments: list!(),
+        ret: Some(ret),
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
 pub fn atomic_write(ptr: ValueExpr, src: ValueExpr, next: u32) -> Terminator {
     Terminator::CallIntrinsic {
         intrinsic: Intrinsic::AtomicWrite,
@@ -129,6 +383,29 @@ pub fn atomic_read(dest: PlaceExpr, ptr: ValueExpr, next: u32) -> Terminator {
     }
 }
 
+// Non-atomic load of a `pty` through `ptr`.
+// Example usage:
+// `typed_read(local(0), load(local(1)), <u32>::get_ptype(), 1)`
+pub fn typed_read(dest: PlaceExpr, ptr: ValueExpr, pty: PlaceType, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::TypedRead(pty),
+        arguments: list!(ptr),
+        ret: Some(dest),
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+// Non-atomic store of `src`, which must have type `pty.ty`, through `ptr`.
+pub fn typed_write(ptr: ValueExpr, src: ValueExpr, pty: PlaceType, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::TypedWrite(pty),
+        arguments: list!(ptr, src),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+// `dest` must be of type `(T, bool)`; it receives the previous value and whether the exchange succeeded.
 pub fn compare_exchange(dest: PlaceExpr, ptr: ValueExpr, current: ValueExpr, next_val: ValueExpr, next: u32) -> Terminator {
     Terminator::CallIntrinsic { 
         intrinsic: Intrinsic::CompareExchange,
@@ -138,6 +415,16 @@ pub fn compare_exchange(dest: PlaceExpr, ptr: ValueExpr, current: ValueExpr, nex
     }
 }
 
+// Like `compare_exchange`, but the exchange may fail spuriously even if the comparison succeeds.
+pub fn compare_exchange_weak(dest: PlaceExpr, ptr: ValueExpr, current: ValueExpr, next_val: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::CompareExchangeWeak,
+        arguments: list!(ptr, current, next_val),
+        ret: Some(dest),
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
 pub fn create_lock(ret: PlaceExpr, next: u32) -> Terminator {
     Terminator::CallIntrinsic {
         intrinsic: Intrinsic::Lock(LockIntrinsic::Create),
@@ -164,3 +451,84 @@ pub fn release(lock_id: ValueExpr, next: u32) -> Terminator {
         next_block: Some(BbName(Name::from_internal(next)))
     }
 }
+
+pub fn create_rwlock(ret: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::RwLock(RwLockIntrinsic::Create),
+        arguments: list!(),
+        ret: Some(ret),
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn acquire_read(rwlock_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::RwLock(RwLockIntrinsic::AcquireRead),
+        arguments: list!(rwlock_id),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn release_read(rwlock_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::RwLock(RwLockIntrinsic::ReleaseRead),
+        arguments: list!(rwlock_id),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn acquire_write(rwlock_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::RwLock(RwLockIntrinsic::AcquireWrite),
+        arguments: list!(rwlock_id),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn release_write(rwlock_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::RwLock(RwLockIntrinsic::ReleaseWrite),
+        arguments: list!(rwlock_id),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn create_condvar(ret: PlaceExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Condvar(CondvarIntrinsic::Create),
+        arguments: list!(),
+        ret: Some(ret),
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn condvar_wait(condvar_id: ValueExpr, lock_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Condvar(CondvarIntrinsic::Wait),
+        arguments: list!(condvar_id, lock_id),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn notify_one(condvar_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Condvar(CondvarIntrinsic::NotifyOne),
+        arguments: list!(condvar_id),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
+
+pub fn notify_all(condvar_id: ValueExpr, next: u32) -> Terminator {
+    Terminator::CallIntrinsic {
+        intrinsic: Intrinsic::Condvar(CondvarIntrinsic::NotifyAll),
+        arguments: list!(condvar_id),
+        ret: None,
+        next_block: Some(BbName(Name::from_internal(next)))
+    }
+}
@@ -4,7 +4,7 @@ pub fn layout(size: Size, align: Align) -> Layout {
     Layout {
         size,
         align,
-        inhabited: true, // currently everything is inhabited (enums don't exist yet).
+        inhabited: true, // uninhabited layouts are not supported by the builders yet.
     }
 }
 
@@ -12,6 +12,17 @@ pub fn int_ty(signed: Signedness, size: Size) -> Type {
     Type::Int(IntType { signed, size })
 }
 
+// `usize` and `isize` on the target `T`. `<usize>::get_type()` always uses `DefaultTarget`.
+// Example usage:
+// `usize_ty::<Target32>()`
+pub fn usize_ty<T: Target>() -> Type {
+    int_ty(Unsigned, T::PTR_SIZE)
+}
+
+pub fn isize_ty<T: Target>() -> Type {
+    int_ty(Signed, T::PTR_SIZE)
+}
+
 pub fn bool_ty() -> Type {
     Type::Bool
 }
@@ -38,6 +49,10 @@ pub fn raw_ptr_ty(pointee: Layout) -> Type {
     Type::Ptr(PtrType::Raw { pointee })
 }
 
+pub fn fn_ptr_ty() -> Type {
+    Type::Ptr(PtrType::FnPtr)
+}
+
 pub fn tuple_ty(f: &[(Size, Type)], size: Size) -> Type {
     Type::Tuple {
         fields: f.iter().copied().collect(),
@@ -54,6 +69,61 @@ pub fn union_ty(f: &[(Size, Type)], size: Size) -> Type {
     }
 }
 
+pub fn union_ptype(f: &[(Size, Type)], size: Size, align: Align) -> PlaceType {
+    ptype(union_ty(f, size), align)
+}
+
+// A union of `()` and `ty`, like `MaybeUninit<ty>`. Its bytes may be uninitialized.
+// Example usage:
+// `maybe_uninit_ty(<bool>::get_type())`
+pub fn maybe_uninit_ty(ty: Type) -> Type {
+    let ty_size = ty.size::<BasicMemory<DefaultTarget>>();
+    union_ty(&[(size(0), <()>::get_type()), (size(0), ty)], ty_size)
+}
+
+// The variant index is stored as a tag of type `Tag` at `tag_offset`.
+// Example usage, for an `Option<i32>`-like enum:
+// `enum_ty::<u32>(&[<()>::get_type(), tuple_ty(&[(size(4), <i32>::get_type())], size(8))], size(0), size(8))`
+pub fn enum_ty<Tag: TypeConv>(variants: &[Type], tag_offset: Size, size: Size) -> Type {
+    let Type::Int(tag_ty) = Tag::get_type() else {
+        panic!("enum_ty requires an integer tag type!");
+    };
+    Type::Enum {
+        variants: variants.iter().copied().collect(),
+        tag_encoding: TagEncoding::Direct { offset: tag_offset, tag_ty },
+        size,
+    }
+}
+
+// The variant `untagged_variant` is encoded by its data alone; every other variant `idx`
+// is encoded by storing its `(idx, value)` niche value as a `Tag` at `tag_offset`.
+// Example usage, for an `Option<&i32>`-like enum that represents `None` as the null pointer:
+// `niche_enum_ty::<usize>(&[<()>::get_type(), tuple_ty(&[(size(0), <&i32>::get_type())], size(8))], 1, size(0), &[(0, 0)], size(8))`
+pub fn niche_enum_ty<Tag: TypeConv>(variants: &[Type], untagged_variant: u32, tag_offset: Size, niche_values: &[(u32, i128)], size: Size) -> Type {
+    let Type::Int(tag_ty) = Tag::get_type() else {
+        panic!("niche_enum_ty requires an integer tag type!");
+    };
+    Type::Enum {
+        variants: variants.iter().copied().collect(),
+        tag_encoding: TagEncoding::Niche {
+            untagged_variant: untagged_variant.into(),
+            offset: tag_offset,
+            tag_ty,
+            niche_values: niche_values.iter().map(|&(idx, val)| (Int::from(idx), Int::from(val))).collect(),
+        },
+        size,
+    }
+}
+
+// The uninhabited type `!`, an enum without variants. Loading a value of this type is always UB.
+pub fn never_ty() -> Type {
+    Type::Enum {
+        variants: list![],
+        tag_encoding: TagEncoding::Direct { offset: Size::ZERO, tag_ty: IntType { signed: Unsigned, size: Size::from_bytes_const(1) } },
+        size: Size::ZERO,
+    }
+}
+
 pub fn array_ty(elem: Type, count: impl Into<Int>) -> Type {
     Type::Array {
         elem: GcCow::new(elem),
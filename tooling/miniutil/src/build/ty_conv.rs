@@ -25,6 +25,25 @@ pub trait TypeConv {
     }
 }
 
+// locals!(T1, T2, ...)
+// is syntactic sugar for
+// [<T1>::get_ptype(), <T2>::get_ptype(), ...]
+//
+// The locals can also be given names, which are only there for readability:
+// locals!(x: T1, y: T2, ...)
+//
+// Example usage:
+// `let locals = locals!(u32, bool);`
+// `let locals = locals!(counter: u32, done: bool);`
+pub macro locals {
+    ($($name:ident : $ty:ty),* $(,)?) => {
+        [$(<$ty>::get_ptype()),*]
+    },
+    ($($ty:ty),* $(,)?) => {
+        [$(<$ty>::get_ptype()),*]
+    },
+}
+
 macro_rules! type_conv_int_impl {
     ($ty:ty, $signed:expr, $size:expr, $align:expr) => {
         impl TypeConv for $ty {
@@ -56,20 +75,20 @@ type_conv_int_impl!(i32, Signed, size(4), align(4));
 type_conv_int_impl!(i64, Signed, size(8), align(8));
 type_conv_int_impl!(i128, Signed, size(16), align(8));
 
-// We use `BasicMemory` to run a Program (see the `run` module),
+// We use `DefaultTarget` to run a Program (see the `run` module),
 // hence we have to use its PTR_SIZE for `usize` and `isize`.
-type_conv_int_impl!(usize, Unsigned, <BasicMemory>::PTR_SIZE, <BasicMemory>::PTR_ALIGN);
-type_conv_int_impl!(isize, Signed, <BasicMemory>::PTR_SIZE, <BasicMemory>::PTR_ALIGN);
+type_conv_int_impl!(usize, Unsigned, DefaultTarget::PTR_SIZE, DefaultTarget::PTR_ALIGN);
+type_conv_int_impl!(isize, Signed, DefaultTarget::PTR_SIZE, DefaultTarget::PTR_ALIGN);
 
 impl<T: TypeConv> TypeConv for *const T {
     fn get_type() -> Type {
         raw_ptr_ty(T::get_layout())
     }
     fn get_size() -> Size {
-        <BasicMemory>::PTR_SIZE
+        DefaultTarget::PTR_SIZE
     }
     fn get_align() -> Align {
-        <BasicMemory>::PTR_ALIGN
+        DefaultTarget::PTR_ALIGN
     }
 }
 
@@ -78,10 +97,10 @@ impl<T: TypeConv> TypeConv for *mut T {
         raw_ptr_ty(T::get_layout())
     }
     fn get_size() -> Size {
-        <BasicMemory>::PTR_SIZE
+        DefaultTarget::PTR_SIZE
     }
     fn get_align() -> Align {
-        <BasicMemory>::PTR_ALIGN
+        DefaultTarget::PTR_ALIGN
     }
 }
 
@@ -90,10 +109,10 @@ impl<T: TypeConv> TypeConv for &T {
         ref_ty(T::get_layout())
     }
     fn get_size() -> Size {
-        <BasicMemory>::PTR_SIZE
+        DefaultTarget::PTR_SIZE
     }
     fn get_align() -> Align {
-        <BasicMemory>::PTR_ALIGN
+        DefaultTarget::PTR_ALIGN
     }
 }
 
@@ -102,10 +121,10 @@ impl<T: TypeConv> TypeConv for &mut T {
         ref_mut_ty(T::get_layout())
     }
     fn get_size() -> Size {
-        <BasicMemory>::PTR_SIZE
+        DefaultTarget::PTR_SIZE
     }
     fn get_align() -> Align {
-        <BasicMemory>::PTR_ALIGN
+        DefaultTarget::PTR_ALIGN
     }
 }
 
@@ -144,3 +163,23 @@ impl TypeConv for () {
         align(1)
     }
 }
+
+// Tuples are laid out like `#[repr(C)]` structs.
+impl<A: TypeConv, B: TypeConv> TypeConv for (A, B) {
+    fn get_type() -> Type {
+        let offset = round_up(A::get_size(), B::get_align());
+        tuple_ty(&[(size(0), A::get_type()), (offset, B::get_type())], Self::get_size())
+    }
+    fn get_size() -> Size {
+        let offset = round_up(A::get_size(), B::get_align());
+        round_up(offset + B::get_size(), Self::get_align())
+    }
+    fn get_align() -> Align {
+        std::cmp::max(A::get_align(), B::get_align())
+    }
+}
+
+fn round_up(s: Size, a: Align) -> Size {
+    let a = a.bytes();
+    size((s.bytes() + a - 1) / a * a)
+}
@@ -66,19 +66,27 @@ pub(super) fn fmt_global_name(g: GlobalName) -> String {
     format!("global({id})")
 }
 
-fn fmt_constant(c: Constant) -> FmtExpr {
+// Integer literals are always suffixed with their type, so their value is unambiguous in the text.
+// The canonical form is the full decimal value, with a leading `-` if negative, followed by `_` and the type:
+// `7_i32`, `-128_i8`, `340282366920938463463374607431768211455_u128`.
+fn fmt_constant(c: Constant, ty: Type) -> FmtExpr {
     match c {
-        Constant::Int(int) => FmtExpr::Atomic(int.to_string()),
+        Constant::Int(int) => {
+            let Type::Int(int_ty) = ty else {
+                panic!("integer constant with non-integer type");
+            };
+            let int_ty = fmt_int_type(int_ty);
+            FmtExpr::Atomic(format!("{int}_{int_ty}"))
+        }
         Constant::Bool(b) => FmtExpr::Atomic(b.to_string()),
         Constant::GlobalPointer(relocation) => fmt_relocation(relocation),
         Constant::FnPointer(fn_name) => FmtExpr::Atomic(fmt_fn_name(fn_name)),
-        Constant::Variant { .. } => panic!("enums are unsupported!"),
     }
 }
 
 pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> FmtExpr {
     match v {
-        ValueExpr::Constant(c, _ty) => fmt_constant(c),
+        ValueExpr::Constant(c, ty) => fmt_constant(c, ty),
         ValueExpr::Tuple(l, t) => {
             let (lparen, rparen) = match t {
                 Type::Array { .. } => ('[', ']'),
@@ -99,6 +107,19 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
             let expr = fmt_value_expr(expr.extract(), comptypes).to_string();
             FmtExpr::NonAtomic(format!("{union_ty} {{ field{field}: {expr} }}"))
         }
+        ValueExpr::Variant {
+            idx,
+            data,
+            enum_ty,
+        } => {
+            let enum_ty = fmt_type(enum_ty, comptypes).to_string();
+            let data = fmt_value_expr(data.extract(), comptypes).to_string();
+            FmtExpr::NonAtomic(format!("{enum_ty}::variant{idx}({data})"))
+        }
+        ValueExpr::GetDiscriminant { place, .. } => {
+            let place = fmt_place_expr(place.extract(), comptypes).to_string();
+            FmtExpr::Atomic(format!("discriminant({place})"))
+        }
         ValueExpr::Load {
             destructive,
             source,
@@ -144,10 +165,18 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
                     let int_ty = fmt_int_type(int_ty);
                     FmtExpr::NonAtomic(format!("-<{int_ty}>({operand})"))
                 }
+                UnOp::Int(UnOpInt::Abs, int_ty) => {
+                    let int_ty = fmt_int_type(int_ty);
+                    FmtExpr::Atomic(format!("abs<{int_ty}>({operand})"))
+                }
                 UnOp::Int(UnOpInt::Cast, int_ty) => {
                     let int_ty = fmt_int_type(int_ty);
                     FmtExpr::Atomic(format!("int2int<{int_ty}>({operand})"))
                 }
+                UnOp::Bool2Int(int_ty) => {
+                    let int_ty = fmt_int_type(int_ty);
+                    FmtExpr::Atomic(format!("bool2int<{int_ty}>({operand})"))
+                }
                 UnOp::Ptr2Ptr(ptr_ty) => {
                     let ptr_ty = fmt_ptr_type(ptr_ty).to_string();
                     FmtExpr::Atomic(format!("ptr2ptr<{ptr_ty}>({operand})"))
@@ -155,9 +184,17 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
                 UnOp::Ptr2Int => {
                     FmtExpr::Atomic(format!("ptr2int({operand})"))
                 }
-                UnOp::Int2Ptr(ptr_ty) => {
+                UnOp::Int2Ptr(ptr_ty, provenance) => {
                     let ptr_ty = fmt_ptr_type(ptr_ty).to_string();
-                    FmtExpr::Atomic(format!("int2ptr<{ptr_ty}>({operand})"))
+                    let cast = match provenance {
+                        Int2PtrProvenance::Exposed => "int2ptr",
+                        Int2PtrProvenance::Invalid => "int2ptr_invalid",
+                    };
+                    FmtExpr::Atomic(format!("{cast}<{ptr_ty}>({operand})"))
+                }
+                UnOp::Transmute(new_ty) => {
+                    let new_ty = fmt_type(new_ty, comptypes).to_string();
+                    FmtExpr::Atomic(format!("transmute<{new_ty}>({operand})"))
                 }
             }
         }
@@ -166,15 +203,28 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
             left,
             right,
         } => {
+            let int_ty = fmt_int_type(int_ty).to_string();
+
+            // `min` and `max` are formatted like function calls.
+            let fn_op = match int_op {
+                BinOpInt::Min => Some("min"),
+                BinOpInt::Max => Some("max"),
+                _ => None,
+            };
+            if let Some(fn_op) = fn_op {
+                let l = fmt_value_expr(left.extract(), comptypes).to_string();
+                let r = fmt_value_expr(right.extract(), comptypes).to_string();
+                return FmtExpr::Atomic(format!("{fn_op}<{int_ty}>({l}, {r})"));
+            }
+
             let int_op = match int_op {
                 BinOpInt::Add => '+',
                 BinOpInt::Sub => '-',
                 BinOpInt::Mul => '*',
                 BinOpInt::Div => '/',
                 BinOpInt::Rem => '%',
+                BinOpInt::Min | BinOpInt::Max => unreachable!(),
             };
-
-            let int_ty = fmt_int_type(int_ty).to_string();
             let int_op = format!("{int_op}<{int_ty}>");
 
             let l = fmt_value_expr(left.extract(), comptypes).to_atomic_string();
@@ -201,6 +251,21 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
 
             FmtExpr::NonAtomic(format!("{l} {rel} {r}"))
         }
+        ValueExpr::BinOp {
+            operator: BinOp::PtrRel(rel),
+            left,
+            right,
+        } => {
+            let rel = match rel {
+                PtrRel::Eq => "==<ptr>",
+                PtrRel::Ne => "!=<ptr>",
+            };
+
+            let l = fmt_value_expr(left.extract(), comptypes).to_atomic_string();
+            let r = fmt_value_expr(right.extract(), comptypes).to_atomic_string();
+
+            FmtExpr::NonAtomic(format!("{l} {rel} {r}"))
+        }
         ValueExpr::BinOp {
             operator: BinOp::PtrOffset { inbounds },
             left,
@@ -214,5 +279,14 @@ pub(super) fn fmt_value_expr(v: ValueExpr, comptypes: &mut Vec<CompType>) -> Fmt
             let r = fmt_value_expr(right.extract(), comptypes).to_string();
             FmtExpr::Atomic(format!("{offset_name}({l}, {r})"))
         }
+        ValueExpr::BinOp {
+            operator: BinOp::PtrOffsetFrom,
+            left,
+            right,
+        } => {
+            let l = fmt_value_expr(left.extract(), comptypes).to_string();
+            let r = fmt_value_expr(right.extract(), comptypes).to_string();
+            FmtExpr::Atomic(format!("offset_from({l}, {r})"))
+        }
     }
 }
@@ -2,7 +2,8 @@ use super::*;
 
 // Formats all functions found within the program.
 // All composite types that are used within `prog` will be added to `comptypes` exactly once.
-pub(super) fn fmt_functions(prog: Program, comptypes: &mut Vec<CompType>) -> String {
+// With `start_keyword = false`, the start function and blocks are not marked.
+pub(super) fn fmt_functions(prog: Program, start_keyword: bool, comptypes: &mut Vec<CompType>) -> String {
     let mut fns: Vec<(FnName, Function)> = prog.functions.iter().collect();
 
     // Functions are formatted in the order given by their name.
@@ -10,17 +11,18 @@ pub(super) fn fmt_functions(prog: Program, comptypes: &mut Vec<CompType>) -> Str
 
     let mut out = String::new();
     for (fn_name, f) in fns {
-        let start = prog.start == fn_name;
-        out += &fmt_function(fn_name, f, start, comptypes);
+        let start = start_keyword && prog.start == fn_name;
+        out += &fmt_function(fn_name, f, start, start_keyword, comptypes);
     }
 
     out
 }
 
-fn fmt_function(
+pub(super) fn fmt_function(
     fn_name: FnName,
     f: Function,
     start: bool,
+    start_keyword: bool,
     comptypes: &mut Vec<CompType>,
 ) -> String {
     let fn_name = fmt_fn_name(fn_name).to_string();
@@ -70,7 +72,7 @@ fn fmt_function(
     blocks.sort_by_key(|(BbName(name), _block)| *name);
 
     for (bb_name, bb) in blocks {
-        let start = f.start == bb_name;
+        let start = start_keyword && f.start == bb_name;
         out += &fmt_bb(bb_name, bb, start, comptypes);
     }
     out += "}\n\n";
@@ -78,6 +80,41 @@ fn fmt_function(
     out
 }
 
+// Formats the signature of a function, e.g. `fn f1(_1: i32@align(4)) -> _0: i32@align(4);`.
+pub(super) fn fmt_signature(fn_name: FnName, f: Function, comptypes: &mut Vec<CompType>) -> String {
+    let fn_name = fmt_fn_name(fn_name);
+    let mut fmt_local = |name: LocalName| {
+        let local = fmt_local_name(name);
+        let ptype = fmt_ptype(f.locals.index_at(name), comptypes);
+        format!("{local}: {ptype}")
+    };
+
+    let args: Vec<String> = f.args.iter().map(|(name, _arg_abi)| fmt_local(name)).collect();
+    let args = args.join(", ");
+
+    let ret_str = match f.ret {
+        Some((ret, _arg_abi)) => format!("-> {}", fmt_local(ret)),
+        None => format!("-/>"),
+    };
+
+    format!("fn {fn_name}({args}) {ret_str};\n")
+}
+
+// The functions that `f` calls directly, in the order of their names.
+pub(super) fn callees(f: Function) -> Vec<FnName> {
+    let mut callees: Vec<FnName> = f.blocks.values()
+        .filter_map(|bb| match bb.terminator {
+            Terminator::Call { callee: ValueExpr::Constant(Constant::FnPointer(callee), _), .. }
+            | Terminator::CatchUnwind { callee: ValueExpr::Constant(Constant::FnPointer(callee), _), .. }
+            | Terminator::Become { callee: ValueExpr::Constant(Constant::FnPointer(callee), _), .. } => Some(callee),
+            _ => None,
+        })
+        .collect();
+    callees.sort_by_key(|FnName(name)| *name);
+    callees.dedup();
+    callees
+}
+
 fn fmt_bb(bb_name: BbName, bb: BasicBlock, start: bool, comptypes: &mut Vec<CompType>) -> String {
     let name = bb_name.0.get_internal();
 
@@ -129,6 +166,7 @@ fn fmt_call(
     arguments: List<ValueExpr>,
     ret: Option<PlaceExpr>,
     next_block: Option<BbName>,
+    unwind_block: Option<BbName>,
     comptypes: &mut Vec<CompType>,
 ) -> String {
     // Format function args
@@ -144,13 +182,18 @@ fn fmt_call(
         None => String::from("_"),
     };
 
-    // Format next block
-    let next = match next_block {
-        Some(next_block) => {
+    // Format next block and cleanup block
+    let next = match (next_block, unwind_block) {
+        (Some(next_block), None) => {
             let next_str = fmt_bb_name(next_block);
             format!(" -> {next_str}")
         }
-        None => String::new(),
+        (next_block, Some(unwind_block)) => {
+            let next_str = next_block.map(fmt_bb_name).unwrap_or_else(|| String::from("_"));
+            let unwind_str = fmt_bb_name(unwind_block);
+            format!(" -> [return: {next_str}, unwind: {unwind_str}]")
+        }
+        (None, None) => String::new(),
     };
 
     format!("    {r} = {callee}({args}){next};")
@@ -186,47 +229,90 @@ fn fmt_terminator(t: Terminator, comptypes: &mut Vec<CompType>) -> String {
             arguments,
             ret,
             next_block,
+            unwind_block,
         } => {
             let callee = fmt_value_expr(callee, comptypes).to_atomic_string();
             let arguments = arguments.iter().map(|(expr, _arg_abi)| expr).collect();
             let ret = ret.map(|(place_expr, _arg_abi)| place_expr);
-            fmt_call(&callee, arguments, ret, next_block, comptypes)
+            fmt_call(&callee, arguments, ret, next_block, unwind_block, comptypes)
+        }
+        Terminator::Become { callee, arguments } => {
+            let callee = fmt_value_expr(callee, comptypes).to_atomic_string();
+            let args: Vec<_> = arguments
+                .iter()
+                .map(|(expr, _arg_abi)| fmt_value_expr(expr, comptypes).to_string())
+                .collect();
+            let args = args.join(", ");
+            format!("    become {callee}({args});")
         }
         Terminator::Return => {
             format!("    return;")
         }
+        Terminator::Resume => {
+            format!("    resume;")
+        }
+        Terminator::CatchUnwind {
+            callee,
+            ret,
+            next_block,
+        } => {
+            fmt_call("catch_unwind", list![callee], Some(ret), Some(next_block), None, comptypes)
+        }
         Terminator::CallIntrinsic {
             intrinsic,
             arguments,
             ret,
             next_block,
         } => {
-            let callee = match intrinsic {
-                Intrinsic::Exit => "exit",
-                Intrinsic::PrintStdout => "print",
-                Intrinsic::PrintStderr => "eprint",
-                Intrinsic::Allocate => "allocate",
-                Intrinsic::Deallocate => "deallocate",
-                Intrinsic::Spawn => "spawn",
-                Intrinsic::Join => "join",
-                Intrinsic::AtomicWrite => "atomic-write",
-                Intrinsic::AtomicRead => "atomic-read",
-                Intrinsic::CompareExchange => "compare-exchange",
-                Intrinsic::Lock(LockIntrinsic::Acquire) => "lock-acquire",
-                Intrinsic::Lock(LockIntrinsic::Create) => "lock-create",
-                Intrinsic::Lock(LockIntrinsic::Release) => "lock-release",
+            let callee: String = match intrinsic {
+                Intrinsic::TypedRead(pty) => format!("read<{}>", fmt_ptype(pty, comptypes)),
+                Intrinsic::TypedWrite(pty) => format!("write<{}>", fmt_ptype(pty, comptypes)),
+                Intrinsic::Exit => "exit".into(),
+                Intrinsic::Abort => "abort".into(),
+                Intrinsic::Panic => "panic".into(),
+                Intrinsic::Assert(message) => format!("assert<{}>", fmt_global_name(message)),
+                Intrinsic::PrintStdout => "print".into(),
+                Intrinsic::PrintStderr => "eprint".into(),
+                Intrinsic::Allocate => "allocate".into(),
+                Intrinsic::Deallocate => "deallocate".into(),
+                Intrinsic::AllocSize => "alloc-size".into(),
+                Intrinsic::Reallocate => "reallocate".into(),
+                Intrinsic::WriteBytes => "write_bytes".into(),
+                Intrinsic::AlignOffset => "align_offset".into(),
+                Intrinsic::Likely => "likely".into(),
+                Intrinsic::Unlikely => "unlikely".into(),
+                Intrinsic::BlackBox => "black_box".into(),
+                Intrinsic::Spawn => "spawn".into(),
+                Intrinsic::Join => "join".into(),
+                Intrinsic::ThreadId => "thread-id".into(),
+                Intrinsic::AtomicWrite => "atomic-write".into(),
+                Intrinsic::AtomicRead => "atomic-read".into(),
+                Intrinsic::CompareExchange => "compare-exchange".into(),
+                Intrinsic::CompareExchangeWeak => "compare-exchange-weak".into(),
+                Intrinsic::Lock(LockIntrinsic::Acquire) => "lock-acquire".into(),
+                Intrinsic::Lock(LockIntrinsic::Create) => "lock-create".into(),
+                Intrinsic::Lock(LockIntrinsic::Release) => "lock-release".into(),
+                Intrinsic::RwLock(RwLockIntrinsic::Create) => "rwlock-create".into(),
+                Intrinsic::RwLock(RwLockIntrinsic::AcquireRead) => "rwlock-acquire-read".into(),
+                Intrinsic::RwLock(RwLockIntrinsic::ReleaseRead) => "rwlock-release-read".into(),
+                Intrinsic::RwLock(RwLockIntrinsic::AcquireWrite) => "rwlock-acquire-write".into(),
+                Intrinsic::RwLock(RwLockIntrinsic::ReleaseWrite) => "rwlock-release-write".into(),
+                Intrinsic::Condvar(CondvarIntrinsic::Create) => "condvar-create".into(),
+                Intrinsic::Condvar(CondvarIntrinsic::Wait) => "condvar-wait".into(),
+                Intrinsic::Condvar(CondvarIntrinsic::NotifyOne) => "condvar-notify-one".into(),
+                Intrinsic::Condvar(CondvarIntrinsic::NotifyAll) => "condvar-notify-all".into(),
             };
-            fmt_call(callee, arguments, ret, next_block, comptypes)
+            fmt_call(&callee, arguments, ret, next_block, None, comptypes)
         }
     }
 }
 
-fn fmt_bb_name(bb: BbName) -> String {
+pub(crate) fn fmt_bb_name(bb: BbName) -> String {
     let id = bb.0.get_internal();
     format!("bb{id}")
 }
 
-pub(super) fn fmt_fn_name(fn_name: FnName) -> String {
+pub fn fmt_fn_name(fn_name: FnName) -> String {
     let id = fn_name.0.get_internal();
     format!("f{id}")
 }
@@ -29,8 +29,9 @@ fn fmt_global(gname: GlobalName, global: Global) -> String {
     let gname_str = fmt_global_name(gname);
     let bytes_str = fmt_bytes(global.bytes);
     let align = global.align.bytes();
+    let thread_local = if global.thread_local { "thread_local " } else { "" };
     let mut out = format!(
-        "{gname_str} {{
+        "{thread_local}{gname_str} {{
   bytes = [{bytes_str}],
   align = {align} bytes,\n"
     );
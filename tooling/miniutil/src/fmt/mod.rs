@@ -5,6 +5,8 @@ use expr::*;
 
 mod function;
 use function::*;
+pub(crate) use function::fmt_bb_name;
+pub use function::fmt_fn_name;
 
 mod ty;
 use ty::*;
@@ -12,6 +14,38 @@ use ty::*;
 mod global;
 use global::*;
 
+mod wf;
+pub use wf::fmt_wf_error;
+
+mod termination;
+pub use termination::fmt_termination_info;
+
+// Format a place expression on its own.
+// Composite types are named by their first occurrence within this place only.
+pub(crate) fn fmt_place(place: PlaceExpr) -> String {
+    fmt_place_expr(place, &mut Vec::new()).to_string()
+}
+
+// Controls the layout of a formatted program.
+pub struct FmtOptions {
+    // The number of spaces per indentation level.
+    pub indent: usize,
+    // Whether the start function and the start blocks are marked with the `start` keyword.
+    pub start_keyword: bool,
+    // Whether the composite types are printed before the functions.
+    pub type_table: bool,
+}
+
+impl Default for FmtOptions {
+    fn default() -> Self {
+        FmtOptions {
+            indent: 2,
+            start_keyword: true,
+            type_table: true,
+        }
+    }
+}
+
 // Print a program to stdout.
 pub fn dump_program(prog: Program) {
     let s = fmt_program(prog);
@@ -20,11 +54,66 @@ pub fn dump_program(prog: Program) {
 
 // Format a program into a string.
 pub fn fmt_program(prog: Program) -> String {
-    let mut comptypes: Vec<CompType> = Vec::new();
+    fmt_program_with_options(prog, &FmtOptions::default())
+}
 
-    let functions_string = fmt_functions(prog, &mut comptypes);
-    let comptypes_string = fmt_comptypes(comptypes);
+// Format a program into a string, using the given options.
+pub fn fmt_program_with_options(prog: Program, options: &FmtOptions) -> String {
+    let functions_string = fmt_with_comptypes(options.type_table, |comptypes| {
+        fmt_functions(prog, options.start_keyword, comptypes)
+    });
     let globals_string = fmt_globals(prog.globals);
 
-    comptypes_string + &functions_string + &globals_string
+    reindent(&(functions_string + &globals_string), options.indent)
+}
+
+// Format a single function, preceded by the composite types it uses and the signatures of the functions it calls.
+pub fn fmt_function_with_callees(prog: Program, fn_name: FnName) -> String {
+    fmt_with_comptypes(true, |comptypes| {
+        let f = prog.functions.index_at(fn_name);
+        let mut out = String::new();
+        for callee in callees(f) {
+            // Calls to functions that do not exist are ill-formed, but we still want to format them.
+            if let Some(callee_fn) = prog.functions.get(callee) {
+                out += &fmt_signature(callee, callee_fn, comptypes);
+            }
+        }
+        if !out.is_empty() {
+            out.push('\n');
+        }
+        out + &fmt_function(fn_name, f, prog.start == fn_name, true, comptypes)
+    })
+}
+
+// Runs `fmt`, and prepends the table of composite types used by it if `type_table` is set.
+// The composite types are numbered in a canonical order, independent of where they are used.
+fn fmt_with_comptypes(type_table: bool, fmt: impl Fn(&mut Vec<CompType>) -> String) -> String {
+    // Find all composite types, including those nested in other composite types.
+    let mut comptypes = Vec::new();
+    fmt(&mut comptypes);
+    fmt_comptypes(&mut comptypes);
+    sort_comptypes(&mut comptypes);
+
+    let out = fmt(&mut comptypes);
+    match type_table {
+        true => fmt_comptypes(&mut comptypes) + &out,
+        false => out,
+    }
+}
+
+// The formatting functions indent by two spaces per level.
+// This replaces each level by `indent` spaces.
+fn reindent(s: &str, indent: usize) -> String {
+    if indent == 2 {
+        return s.to_string();
+    }
+
+    let mut out = String::new();
+    for line in s.split_inclusive('\n') {
+        let content = line.trim_start_matches(' ');
+        let levels = (line.len() - content.len()) / 2;
+        out += &" ".repeat(levels * indent);
+        out += content;
+    }
+    out
 }
@@ -0,0 +1,16 @@
+use super::*;
+
+// Describe how a program terminated, e.g. `UB: division by zero`.
+// Callers do not have to match on `TerminationInfo` themselves. Since it is `non_exhaustive`,
+// variants that are not handled here yet fall back to their `Debug` representation.
+pub fn fmt_termination_info(info: TerminationInfo) -> String {
+    match info {
+        TerminationInfo::Ub(msg) => format!("UB: {}", msg.get_internal()),
+        TerminationInfo::MachineStop { code } => format!("program exited with code {code}"),
+        TerminationInfo::IllFormed(err) => format!("ERR: program not well-formed: {}", fmt_wf_error(err)),
+        TerminationInfo::Deadlock => "ERR: deadlock, no thread can make progress".to_string(),
+        TerminationInfo::Abort => "program aborted".to_string(),
+        TerminationInfo::StackOverflow => "ERR: stack overflow".to_string(),
+        info => format!("{info:?}"),
+    }
+}
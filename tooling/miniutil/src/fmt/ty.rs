@@ -11,7 +11,7 @@ pub(super) fn fmt_type(t: Type, comptypes: &mut Vec<CompType>) -> FmtExpr {
         Type::Int(int_ty) => FmtExpr::Atomic(fmt_int_type(int_ty)),
         Type::Ptr(ptr_ty) => fmt_ptr_type(ptr_ty),
         Type::Bool => FmtExpr::Atomic(String::from("bool")),
-        Type::Tuple { .. } | Type::Union { .. } => {
+        Type::Tuple { .. } | Type::Union { .. } | Type::Enum { .. } => {
             let comp_ty = CompType(t);
             let comptype_index = get_comptype_index(comp_ty, comptypes);
             FmtExpr::Atomic(fmt_comptype_index(comptype_index))
@@ -20,7 +20,6 @@ pub(super) fn fmt_type(t: Type, comptypes: &mut Vec<CompType>) -> FmtExpr {
             let elem = fmt_type(elem.extract(), comptypes).to_string();
             FmtExpr::Atomic(format!("[{elem}; {count}]"))
         }
-        Type::Enum { .. } => panic!("enums are unsupported!"),
     }
 }
 
@@ -76,7 +75,7 @@ fn fmt_layout(layout: Layout) -> String {
 // composite types
 /////////////////////
 
-// A "composite" type is a union or tuple (enums aren't yet supported).
+// A "composite" type is a union, tuple or enum.
 // Composite types will be printed separately above the functions, as inlining them would be hard to read.
 // During formatting, the list of composite types we encounter will be stored in `comptypes`.
 #[derive(PartialEq, Eq, Clone, Copy)]
@@ -107,8 +106,13 @@ fn fmt_comptype_index(comptype_index: CompTypeIndex) -> String {
     format!("T{id}")
 }
 
+// Sorts composite types by their structure, so that their names do not depend on the order in which they were found.
+pub(super) fn sort_comptypes(comptypes: &mut [CompType]) {
+    comptypes.sort_by_cached_key(|CompType(ty)| format!("{ty:?}"));
+}
+
 // Formats all composite types.
-pub(super) fn fmt_comptypes(mut comptypes: Vec<CompType>) -> String {
+pub(super) fn fmt_comptypes(comptypes: &mut Vec<CompType>) -> String {
     let mut out = String::new();
     let mut i = 0;
     while i < comptypes.len() {
@@ -117,7 +121,7 @@ pub(super) fn fmt_comptypes(mut comptypes: Vec<CompType>) -> String {
 
         // A call to `fmt_comptype` might find new `CompTypes` and push them to `comptypes`.
         // Hence, we cannot use an iterator here.
-        let s = &*fmt_comptype(comptype_index, c, &mut comptypes);
+        let s = &*fmt_comptype(comptype_index, c, comptypes);
 
         out += s;
 
@@ -128,6 +132,10 @@ pub(super) fn fmt_comptypes(mut comptypes: Vec<CompType>) -> String {
 }
 
 fn fmt_comptype(i: CompTypeIndex, t: CompType, comptypes: &mut Vec<CompType>) -> String {
+    if let Type::Enum { variants, tag_encoding, size } = t.0 {
+        return fmt_enum(i, variants, tag_encoding, size, comptypes);
+    }
+
     let (keyword, fields, opt_chunks, size) = match t.0 {
         Type::Tuple { fields, size } => ("tuple", fields, None, size),
         Type::Union {
@@ -155,3 +163,30 @@ fn fmt_comptype(i: CompTypeIndex, t: CompType, comptypes: &mut Vec<CompType>) ->
     s += "}\n\n";
     s
 }
+
+fn fmt_enum(i: CompTypeIndex, variants: List<Type>, tag_encoding: TagEncoding, size: Size, comptypes: &mut Vec<CompType>) -> String {
+    let ct = fmt_comptype_index(i).to_string();
+    let size = size.bytes();
+    let (tag_str, niche_values) = match tag_encoding {
+        TagEncoding::Direct { offset, tag_ty } => {
+            let tag_ty = fmt_int_type(tag_ty);
+            (format!("tag {tag_ty} at byte {}", offset.bytes()), list![])
+        }
+        TagEncoding::Niche { offset, tag_ty, niche_values, .. } => {
+            let tag_ty = fmt_int_type(tag_ty);
+            (format!("niche {tag_ty} at byte {}", offset.bytes()), niche_values)
+        }
+    };
+    let mut s = format!("enum {ct} ({size} bytes, {tag_str}) {{\n");
+    for (idx, ty) in variants.iter().enumerate() {
+        let ty = fmt_type(ty, comptypes).to_string();
+        // Variants that are encoded by a niche value show that value.
+        let niche = niche_values.iter()
+            .find(|(i, _)| *i == Int::from(idx))
+            .map(|(_, val)| format!(" = {val}"))
+            .unwrap_or_default();
+        s += &format!("  variant{idx}: {ty}{niche},\n");
+    }
+    s += "}\n\n";
+    s
+}
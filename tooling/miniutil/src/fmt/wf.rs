@@ -0,0 +1,16 @@
+use super::*;
+
+// Format a well-formedness error as `location: message`,
+// e.g. `f0/bb1/stmt0: local _0 is used but not live`.
+pub fn fmt_wf_error(err: WfError) -> String {
+    let msg = err.msg.get_internal();
+    let loc = match err.loc {
+        WfLocation::Program => return msg,
+        WfLocation::Global(global) => fmt_global_name(global),
+        WfLocation::Function(f) => fmt_fn_name(f),
+        WfLocation::Block(f, bb) => format!("{}/{}", fmt_fn_name(f), fmt_bb_name(bb)),
+        WfLocation::Statement(f, bb, idx) => format!("{}/{}/stmt{idx}", fmt_fn_name(f), fmt_bb_name(bb)),
+        WfLocation::Terminator(f, bb) => format!("{}/{}/terminator", fmt_fn_name(f), fmt_bb_name(bb)),
+    };
+    format!("{loc}: {msg}")
+}
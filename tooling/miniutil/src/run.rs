@@ -1,39 +1,290 @@
 use crate::{*, mock_write::MockWrite};
 
+use std::collections::HashMap;
+use std::ops::ControlFlow;
+
 /// Run the program and return its TerminationInfo.
 /// Stdout/stderr are just forwarded to the host.
 pub fn run_program(prog: Program) -> TerminationInfo {
+    run_program_on::<DefaultTarget>(prog)
+}
+
+/// Like `run_program`, but run the program on the target `T` instead of `DefaultTarget`.
+pub fn run_program_on<T: Target>(prog: Program) -> TerminationInfo {
     let out = std::io::stdout();
     let err = std::io::stderr();
 
-    let res: Result<!, TerminationInfo> = run(prog, out, err);
+    let res: Result<!, TerminationInfo> = run::<T>(prog, out, err);
     match res {
         Ok(never) => never,
         Err(t) => t,
     }
 }
 
+/// The stack depth limit of the tools, see `RunConfig::max_stack_depth`.
+/// The machine itself has no limit, so without one, unbounded recursion runs until the host runs out of memory.
+pub const DEFAULT_MAX_STACK_DEPTH: u32 = 1024;
+
+/// Options for running a program that deviate from the defaults of the machine.
+#[derive(Debug, Clone, Copy)]
+pub struct RunConfig {
+    /// The maximal number of stack frames per thread, `DEFAULT_MAX_STACK_DEPTH` by default.
+    /// Exceeding that stops the program with `TerminationInfo::StackOverflow`.
+    /// `None` means there is no limit.
+    pub max_stack_depth: Option<Int>,
+    /// Whether to check that mutable references are unique.
+    pub check_aliasing: bool,
+    /// Whether misaligned memory accesses are UB.
+    pub check_alignment: bool,
+}
+
+impl Default for RunConfig {
+    fn default() -> Self {
+        RunConfig { max_stack_depth: Some(Int::from(DEFAULT_MAX_STACK_DEPTH)), check_aliasing: false, check_alignment: true }
+    }
+}
+
+impl RunConfig {
+    fn apply<M: Memory>(self, machine: &mut Machine<M>) {
+        machine.set_max_stack_depth(self.max_stack_depth);
+        machine.set_check_aliasing(self.check_aliasing);
+        machine.set_check_alignment(self.check_alignment);
+    }
+}
+
+/// Run the program like `run_program`, with the given configuration.
+pub fn run_program_with_config(prog: Program, config: RunConfig) -> TerminationInfo {
+    let out = std::io::stdout();
+    let err = std::io::stderr();
+
+    let res: NdResult<!> = try {
+        let mut machine = Machine::<BasicMemory<DefaultTarget>>::new(prog, DynWrite::new(out), DynWrite::new(err))?;
+        config.apply(&mut machine);
+
+        loop {
+            machine.step()?;
+
+            // Drops everything not reachable from `machine`.
+            mark_and_sweep(&machine);
+        }
+    };
+
+    match res.get_internal() {
+        Ok(never) => never,
+        Err(t) => t,
+    }
+}
+
+/// Run the program like `run_program`, but allow at most `depth` stack frames per thread.
+/// Exceeding that stops the program with `TerminationInfo::StackOverflow`.
+pub fn run_program_with_max_stack_depth(prog: Program, depth: impl Into<Int>) -> TerminationInfo {
+    run_program_with_config(prog, RunConfig { max_stack_depth: Some(depth.into()), ..RunConfig::default() })
+}
+
+/// Run the program like `run_program`, but check that mutable references are unique.
+/// Writing through a mutable reference after a newer mutable reference to the same memory
+/// was created raises UB.
+pub fn run_program_with_aliasing_checks(prog: Program) -> TerminationInfo {
+    run_program_with_config(prog, RunConfig { check_aliasing: true, ..RunConfig::default() })
+}
+
+/// Check whether the program is well-formed, and report the violated requirement if it is not.
+/// Use `fmt::fmt_wf_error` to get a readable description of the error.
+pub fn check_program(prog: Program) -> Result<(), WfError> {
+    check_program_on::<DefaultTarget>(prog)
+}
+
+/// Like `check_program`, but check the program for the target `T`.
+/// This matters for pointer-sized integers, which must have the pointer size of the target.
+pub fn check_program_on<T: Target>(prog: Program) -> Result<(), WfError> {
+    prog.check_wf::<BasicMemory<T>>()
+}
+
 /// Run the program and return stdout as a `Vec<String>`  or a termination info
 /// if it did not terminate correctly. Stderr is just forwarded to the host.
+/// All threads print to the same writer in the order in which the scheduler runs their prints,
+/// and each print is a single step, so lines of different threads never mix and the lines of each
+/// thread appear in program order. The schedule itself is random and cannot be seeded.
 pub fn get_stdout(prog: Program) -> Result<Vec<String>, TerminationInfo> {
+    get_stdout_on::<DefaultTarget>(prog)
+}
+
+/// Like `get_stdout`, but run the program on the target `T` instead of `DefaultTarget`.
+pub fn get_stdout_on<T: Target>(prog: Program) -> Result<Vec<String>, TerminationInfo> {
     let out = MockWrite::new();
     let err = std::io::stderr();
 
-    let res = run(prog, out.clone(), err);
+    let res = run::<T>(prog, out.clone(), err);
+    match res {
+        Ok(never) => never,
+        Err(TerminationInfo::MachineStop { .. }) => Ok(out.into_strings()),
+        Err(info) => Err(info)
+    }
+}
+
+/// Run the program and return the lines it printed to stdout.
+/// Panics with a description of how the program terminated if it did not stop normally,
+/// e.g. because of UB or because it is ill-formed. Stderr is just forwarded to the host.
+pub fn run_expect_ok(prog: Program) -> Vec<String> {
+    let mut out = match get_stdout(prog) {
+        Ok(out) => out,
+        Err(info) => panic!("program did not terminate correctly: {}", crate::fmt::fmt_termination_info(info)),
+    };
+    // Every printed line ends in a newline, so the last "line" is empty.
+    if out.last().is_some_and(|l| l.is_empty()) {
+        out.pop();
+    }
+    out
+}
+
+/// Run the program and return stderr as a `Vec<String>`  or a termination info
+/// if it did not terminate correctly. Stdout is just forwarded to the host.
+pub fn get_stderr(prog: Program) -> Result<Vec<String>, TerminationInfo> {
+    let out = std::io::stdout();
+    let err = MockWrite::new();
+
+    let res = run::<DefaultTarget>(prog, out, err.clone());
     match res {
         Ok(never) => never,
-        Err(TerminationInfo::MachineStop) => Ok(out.into_strings()),
+        Err(TerminationInfo::MachineStop { .. }) => Ok(err.into_strings()),
         Err(info) => Err(info)
     }
 }
 
+/// Run the program and return its exit code or a termination info
+/// if it did not terminate correctly. Stdout/stderr are just forwarded to the host.
+pub fn get_exit_code(prog: Program) -> Result<Int, TerminationInfo> {
+    match run_program(prog) {
+        TerminationInfo::MachineStop { code } => Ok(code),
+        info => Err(info)
+    }
+}
+
+/// The observable behavior of one execution: what was printed to stdout and how the program terminated.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Observation {
+    pub stdout: Vec<String>,
+    pub termination: TerminationInfo,
+}
+
+/// Two executions of the same program that behaved differently.
+/// Executions are identified by their index in `0..num_schedules`.
+#[derive(Debug, Clone)]
+pub struct Divergence {
+    pub first_run: usize,
+    pub first: Observation,
+    pub second_run: usize,
+    pub second: Observation,
+}
+
+/// Run the program `num_schedules` times and check that all executions have the same
+/// observable behavior (see `Observation`). On a mismatch, the first execution and the
+/// first one that differs from it are reported.
+/// Every execution picks its own schedule; stderr is just forwarded to the host.
+pub fn check_deterministic(prog: Program, num_schedules: usize) -> Result<(), Divergence> {
+    if num_schedules == 0 {
+        return Ok(());
+    }
+
+    let first = observe(prog);
+
+    for run in 1..num_schedules {
+        let obs = observe(prog);
+        if obs != first {
+            return Err(Divergence { first_run: 0, first, second_run: run, second: obs });
+        }
+    }
+
+    Ok(())
+}
+
+fn observe(prog: Program) -> Observation {
+    let out = MockWrite::new();
+    let err = std::io::stderr();
+
+    let termination = match run::<DefaultTarget>(prog, out.clone(), err) {
+        Ok(never) => never,
+        Err(info) => info,
+    };
+
+    Observation { stdout: out.into_strings(), termination }
+}
+
+/// An execution found by `explore_all` that raised UB.
+#[derive(Debug, Clone)]
+pub struct FoundUb {
+    /// The UB message.
+    pub msg: String,
+    /// The thread that took each step, up to and including the step that raised UB.
+    pub schedule: Vec<ThreadId>,
+}
+
+/// Run the program once for every way of scheduling its threads, and report the first execution that raises UB.
+/// Whenever more than one thread is enabled, each of them is tried in turn (depth-first).
+/// Every execution is cut off after `max_steps` steps, so only schedules up to that length are covered.
+/// Other non-deterministic choices, like the addresses of allocations, are still made randomly in each execution.
+/// If the program's control flow depends on them, replaying a schedule can reach a point where the scheduled
+/// thread is not enabled; the rest of such a schedule is skipped, so the exploration is not complete for these programs.
+/// Stdout/stderr of the executions are discarded.
+pub fn explore_all(prog: Program, max_steps: usize) -> Result<(), FoundUb> {
+    // The schedule prefixes that still need to be explored.
+    let mut todo = vec![Vec::new()];
+    while let Some(prefix) = todo.pop() {
+        explore_from(prog, prefix, max_steps, &mut todo)?;
+    }
+
+    Ok(())
+}
+
+// Run the program following the schedule `prefix`, and after that always pick the first enabled thread.
+// Schedules that pick another thread after the prefix are added to `todo`.
+fn explore_from(prog: Program, prefix: Vec<ThreadId>, max_steps: usize, todo: &mut Vec<Vec<ThreadId>>) -> Result<(), FoundUb> {
+    let mut schedule = Vec::new();
+
+    let res: NdResult<()> = try {
+        let mut machine = Machine::<BasicMemory<DefaultTarget>>::new(prog, DynWrite::new(MockWrite::new()), DynWrite::new(MockWrite::new()))?;
+        RunConfig::default().apply(&mut machine);
+
+        while schedule.len() < max_steps {
+            let thread = match prefix.get(schedule.len()) {
+                Some(&thread) => thread,
+                None => {
+                    let enabled: Vec<ThreadId> = machine.enabled_threads().iter().collect();
+                    // If no thread is enabled, the program is deadlocked.
+                    let Some((&first, others)) = enabled.split_first() else { break };
+                    for &other in others {
+                        let mut alternative = schedule.clone();
+                        alternative.push(other);
+                        todo.push(alternative);
+                    }
+                    first
+                }
+            };
+            // The replay diverged from the execution that recorded `prefix`, skip it.
+            let Some(prev_thread) = machine.schedule_thread(thread) else { break };
+            schedule.push(thread);
+
+            machine.step_active_thread(prev_thread)?;
+
+            // Drops everything not reachable from `machine`.
+            mark_and_sweep(&machine);
+        }
+    };
+
+    match res.get_internal() {
+        Err(TerminationInfo::Ub(msg)) => Err(FoundUb { msg: msg.get_internal(), schedule }),
+        _ => Ok(()),
+    }
+}
+
 /// Run the program to completion using the given writers for stdout/stderr.
 /// 
-/// We fix `BasicMemory` as a memory for now.
-fn run(prog: Program, stdout: impl GcWrite, stderr: impl GcWrite) -> Result<!, TerminationInfo> {
+/// We fix `BasicMemory` as a memory for now, on the target `T`.
+fn run<T: Target>(prog: Program, stdout: impl GcWrite, stderr: impl GcWrite) -> Result<!, TerminationInfo> {
     let res: NdResult<!> = try {
 
-        let mut machine = Machine::<BasicMemory>::new(prog, DynWrite::new(stdout), DynWrite::new(stderr))?;
+        let mut machine = Machine::<BasicMemory<T>>::new(prog, DynWrite::new(stdout), DynWrite::new(stderr))?;
+        RunConfig::default().apply(&mut machine);
 
         loop {
             machine.step()?;
@@ -46,3 +297,307 @@ fn run(prog: Program, stdout: impl GcWrite, stderr: impl GcWrite) -> Result<!, T
     // Extract the TerminationInfo from the `NdResult<!>`.
     res.get_internal()
 }
+
+/// The state of the machine at the point where it raised UB.
+pub struct UbTrace {
+    /// The UB message.
+    pub msg: String,
+    /// The thread that raised UB.
+    pub thread: ThreadId,
+    /// The function, basic block and statement index (the terminator if it is out-of-bounds)
+    /// that the thread was executing.
+    pub func: FnName,
+    pub block: BbName,
+    pub stmt: Int,
+    /// The last memory access of the step that raised UB, if any.
+    /// For UB that is raised by a memory access, this is the faulting pointer and access size.
+    pub access: Option<(Address, Size)>,
+    /// The start address and the contents of the allocation containing the faulting pointer.
+    pub allocation: Option<(Address, List<AbstractByte<BasicProvenance>>)>,
+}
+
+/// Run the program like `run_program`, but on UB, return the state of the machine at that point.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_trace(prog: Program) -> Result<TerminationInfo, UbTrace> {
+    let out = std::io::stdout();
+    let err = std::io::stderr();
+
+    let mut machine = match Machine::<BasicMemory<DefaultTarget>>::new(prog, DynWrite::new(out), DynWrite::new(err)).get_internal() {
+        Ok(machine) => machine,
+        Err(info) => return Ok(info),
+    };
+    RunConfig::default().apply(&mut machine);
+
+    loop {
+        match machine.step().get_internal() {
+            Ok(()) => {},
+            Err(TerminationInfo::Ub(msg)) => return Err(UbTrace::new(&machine, msg)),
+            Err(info) => return Ok(info),
+        }
+
+        // Drops everything not reachable from `machine`.
+        mark_and_sweep(&machine);
+    }
+}
+
+impl UbTrace {
+    fn new(machine: &Machine<BasicMemory<DefaultTarget>>, msg: minirust_rs::prelude::String) -> Self {
+        let (thread, func, block, stmt) = machine.location();
+
+        let access = machine.last_access();
+        let allocation = access.and_then(|(addr, _)| machine.inspect_memory(addr));
+
+        UbTrace {
+            msg: msg.get_internal(),
+            thread,
+            func,
+            block,
+            stmt,
+            access,
+            allocation,
+        }
+    }
+}
+
+impl std::fmt::Display for UbTrace {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "UB: {}", self.msg)?;
+
+        let func = crate::fmt::fmt_fn_name(self.func);
+        let block = crate::fmt::fmt_bb_name(self.block);
+        writeln!(f, "  in thread {}, {func}, {block}, statement {}", self.thread, self.stmt)?;
+
+        let Some((addr, len)) = self.access else {
+            return writeln!(f, "  no memory access");
+        };
+        writeln!(f, "  last access: {} bytes at {:#x}", len.bytes(), addr.try_to_usize().unwrap())?;
+
+        let Some((start, bytes)) = self.allocation else {
+            return writeln!(f, "  no allocation at this address");
+        };
+
+        writeln!(f, "  allocation at {:#x}:", start.try_to_usize().unwrap())?;
+        fmt_byte_dump(f, bytes)
+    }
+}
+
+// Dump the bytes in lines of 16 bytes.
+// Below each byte, we show whether it is initialized (`.`), uninitialized (`u`)
+// or carries provenance (`p`).
+fn fmt_byte_dump(f: &mut std::fmt::Formatter<'_>, bytes: List<AbstractByte<BasicProvenance>>) -> std::fmt::Result {
+    let bytes: Vec<_> = bytes.iter().collect();
+    for (i, line) in bytes.chunks(16).enumerate() {
+        let mut data = String::new();
+        let mut status = String::new();
+        for byte in line {
+            let (d, s) = match byte {
+                AbstractByte::Uninit => (String::from("__"), 'u'),
+                AbstractByte::Init(b, None) => (format!("{b:02x}"), '.'),
+                AbstractByte::Init(b, Some(_)) => (format!("{b:02x}"), 'p'),
+            };
+            data += &format!(" {d}");
+            status += &format!(" {s} ");
+        }
+        writeln!(f, "    {:#06x}:{data}", i * 16)?;
+        writeln!(f, "           {status}")?;
+    }
+
+    Ok(())
+}
+
+/// Where the machine is about to take a step.
+pub struct StepInfo<'a> {
+    /// The thread that takes the step.
+    pub thread: ThreadId,
+    /// The function, basic block and statement index (the terminator if it is out-of-bounds)
+    /// that is executed next.
+    pub func: FnName,
+    pub block: BbName,
+    pub stmt: Int,
+    machine: &'a Machine<BasicMemory<DefaultTarget>>,
+}
+
+impl StepInfo<'_> {
+    /// The address and length of the last memory access of the previous step, if any.
+    pub fn last_access(&self) -> Option<(Address, Size)> {
+        self.machine.last_access()
+    }
+
+    /// The `len` bytes starting at `addr`.
+    /// Returns `None` if these bytes are not all inside one allocation.
+    /// Zero bytes are always inside an allocation, even at a dangling address.
+    pub fn bytes(&self, addr: Address, len: Size) -> Option<List<AbstractByte<BasicProvenance>>> {
+        if len.is_zero() {
+            return Some(List::new());
+        }
+
+        let (start, bytes) = self.machine.inspect_memory(addr)?;
+        let offset = addr - start;
+        if offset + len.bytes() > bytes.len() {
+            return None;
+        }
+
+        Some(bytes.subslice_with_length(offset, len.bytes()))
+    }
+
+    /// Which of the `len` bytes starting at `addr` are initialized.
+    /// Returns `None` if these bytes are not all inside one allocation.
+    pub fn is_initialized(&self, addr: Address, len: Size) -> Option<Vec<bool>> {
+        let bytes = self.bytes(addr, len)?;
+        Some(bytes.iter().map(|b| !matches!(b, AbstractByte::Uninit)).collect())
+    }
+}
+
+/// Format an init mask as returned by `StepInfo::is_initialized`, using the same
+/// symbols as the byte dump of `UbTrace`: `.` for initialized, `u` for uninitialized bytes.
+pub fn fmt_init_mask(mask: &[bool]) -> String {
+    mask.iter().map(|&init| if init { '.' } else { 'u' }).collect()
+}
+
+/// Run the program and call `hook` before each step.
+/// The hook can also query the memory through the `StepInfo`.
+/// The hook can stop the execution by returning `ControlFlow::Break`, in which case `None` is returned.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_with_hook(prog: Program, hook: impl FnMut(&StepInfo) -> ControlFlow<()>) -> Option<TerminationInfo> {
+    run_program_with_config_and_hook(prog, RunConfig::default(), hook)
+}
+
+/// Like `run_program_with_hook`, with the given configuration.
+pub fn run_program_with_config_and_hook(prog: Program, config: RunConfig, mut hook: impl FnMut(&StepInfo) -> ControlFlow<()>) -> Option<TerminationInfo> {
+    let out = std::io::stdout();
+    let err = std::io::stderr();
+
+    let res: NdResult<Option<!>> = try {
+        let mut machine = Machine::<BasicMemory<DefaultTarget>>::new(prog, DynWrite::new(out), DynWrite::new(err))?;
+        config.apply(&mut machine);
+
+        loop {
+            let prev_thread = machine.schedule()?;
+
+            let (thread, func, block, stmt) = machine.location();
+            let info = StepInfo { thread, func, block, stmt, machine: &machine };
+            if hook(&info).is_break() {
+                break None;
+            }
+
+            machine.step_active_thread(prev_thread)?;
+
+            // Drops everything not reachable from `machine`.
+            mark_and_sweep(&machine);
+        }
+    };
+
+    match res.get_internal() {
+        Ok(None) => None,
+        Ok(Some(never)) => never,
+        Err(info) => Some(info),
+    }
+}
+
+/// A `Finalize` statement that was executed.
+pub struct FinalizeEvent {
+    /// The thread that executed the statement, and where it is.
+    pub thread: ThreadId,
+    pub func: FnName,
+    pub block: BbName,
+    pub stmt: Int,
+    /// The finalized place and its type.
+    /// The type is `None` if it cannot be determined from the place expression alone,
+    /// e.g. for a field of an enum.
+    pub place: PlaceExpr,
+    pub ty: Option<Type>,
+    pub fn_entry: bool,
+    /// The address of the place, and its contents after finalization.
+    /// The provenance of the contents is the result of retagging.
+    /// (Retagging gives mutable references a new tag if aliasing checks are enabled,
+    /// otherwise it only checks pointers and never changes them.)
+    pub addr: Address,
+    pub bytes: List<AbstractByte<BasicProvenance>>,
+}
+
+/// Run the program like `run_program_with_config`, and record every `Finalize` statement that is executed.
+/// Retagging only changes provenance if `config.check_aliasing` is set.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_with_finalize_log(prog: Program, config: RunConfig) -> (TerminationInfo, Vec<FinalizeEvent>) {
+    let mut log = Vec::new();
+    // The `Finalize` statement executed by the previous step, if any.
+    let mut finalized: Option<(ThreadId, FnName, BbName, Int, PlaceExpr, bool)> = None;
+
+    let info = run_program_with_config_and_hook(prog, config, |info| {
+        if let Some((thread, func, block, stmt, place, fn_entry)) = finalized.take() {
+            // The last access of `Finalize` is storing the retagged value back to the place.
+            // Even a zero-sized store is an access, so there always is one.
+            if let Some((addr, size)) = info.last_access() {
+                log.push(FinalizeEvent {
+                    thread,
+                    func,
+                    block,
+                    stmt,
+                    place,
+                    ty: place_type(place, prog.functions.index_at(func)),
+                    fn_entry,
+                    addr,
+                    bytes: info.bytes(addr, size).unwrap_or_default(),
+                });
+            }
+        }
+
+        let statements = prog.functions.index_at(info.func).blocks.index_at(info.block).statements;
+        if let Some(Statement::Finalize { place, fn_entry }) = statements.get(info.stmt) {
+            finalized = Some((info.thread, info.func, info.block, info.stmt, place, fn_entry));
+        }
+        ControlFlow::Continue(())
+    });
+
+    // The hook never stops the execution early.
+    (info.unwrap(), log)
+}
+
+/// Run the program like `run_program`, and count how often each intrinsic is called.
+/// A call is counted when it is executed, even if it then raises UB or ends the program.
+/// Intrinsics with arguments (like `TypedRead`) are counted separately for each argument.
+/// Stdout/stderr are just forwarded to the host.
+pub fn run_program_with_stats(prog: Program) -> (TerminationInfo, HashMap<Intrinsic, usize>) {
+    let mut stats = HashMap::new();
+
+    let info = run_program_with_hook(prog, |info| {
+        let block = prog.functions.index_at(info.func).blocks.index_at(info.block);
+        if info.stmt == block.statements.len() {
+            if let Terminator::CallIntrinsic { intrinsic, .. } = block.terminator {
+                *stats.entry(intrinsic).or_insert(0) += 1;
+            }
+        }
+        ControlFlow::Continue(())
+    });
+
+    // The hook never stops the execution early.
+    (info.unwrap(), stats)
+}
+
+impl std::fmt::Display for FinalizeEvent {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let place = crate::fmt::fmt_place(self.place);
+        let func = crate::fmt::fmt_fn_name(self.func);
+        let block = crate::fmt::fmt_bb_name(self.block);
+        writeln!(f, "finalize({place}, {}) in thread {}, {func}, {block}, statement {}", self.fn_entry, self.thread, self.stmt)?;
+        writeln!(f, "  place at {:#x}:", self.addr.try_to_usize().unwrap())?;
+        fmt_byte_dump(f, self.bytes)
+    }
+}
+
+/// The type of a place expression within `func`, if it can be determined without running the program.
+fn place_type(place: PlaceExpr, func: Function) -> Option<Type> {
+    match place {
+        PlaceExpr::Local(name) => Some(func.locals.index_at(name).ty),
+        PlaceExpr::Deref { ptype, .. } => Some(ptype.ty),
+        PlaceExpr::Field { root, field } => match place_type(root.extract(), func)? {
+            Type::Tuple { fields, .. } | Type::Union { fields, .. } => Some(fields.get(field)?.1),
+            // Well-formed programs only project to fields of tuples and unions (not e.g. enums).
+            _ => None,
+        },
+        PlaceExpr::Index { root, .. } => match place_type(root.extract(), func)? {
+            Type::Array { elem, .. } => Some(elem.extract()),
+            _ => None,
+        },
+    }
+}